@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use solana_sdk_ids::{ed25519_program, sysvar::instructions as instructions_sysvar};
+use solana_sha256_hasher::hashv;
 
 declare_id!("3hyf5yHncXN2rXjwezK2JxF9s9ohEGjn1GsPByKmyiUj");
 
@@ -24,8 +30,8 @@ pub const GENESIS_PRESENCE: u64 = 3 * SCALING_FACTOR;
 /// Initial competence score for genesis members (10 * SCALING_FACTOR)
 pub const GENESIS_COMPETENCE: u64 = 10 * SCALING_FACTOR;
 
-/// Presence penalty for late registration or late withdrawal
-pub const LATE_PENALTY: u64 = 1 * SCALING_FACTOR;
+/// Full presence penalty for late registration or late withdrawal (at the start time)
+pub const LATE_PENALTY: u64 = SCALING_FACTOR;
 
 /// Presence penalty for ghosting (registered but absent)
 pub const GHOSTING_PENALTY: u64 = 2 * SCALING_FACTOR;
@@ -34,7 +40,96 @@ pub const GHOSTING_PENALTY: u64 = 2 * SCALING_FACTOR;
 pub const OUBLI_PENALTY: u64 = 2 * SCALING_FACTOR;
 
 /// Presence reward for attending an event
-pub const ATTENDANCE_REWARD: u64 = 1 * SCALING_FACTOR;
+pub const ATTENDANCE_REWARD: u64 = SCALING_FACTOR;
+
+/// Maximum number of reviewers in a competence review round
+pub const MAX_ROUND_REVIEWERS: usize = 16;
+
+/// Minimum number of reviews for a round to move the target's competence
+pub const MIN_ROUND_REVIEWERS: usize = 3;
+
+/// Maximum duration of a competence review round (7 days)
+pub const MAX_ROUND_WINDOW: i64 = 7 * SLOT_DURATION;
+
+/// Maximum absolute score a reviewer can submit in a round (whole points)
+pub const MAX_REVIEW_SCORE: i64 = 10;
+
+/// Distance from the consensus (whole points) beyond which a reviewer is an outlier
+pub const OUTLIER_THRESHOLD: i64 = 3;
+
+/// Competence penalty for outlier reviewers (0.5 * SCALING_FACTOR)
+pub const OUTLIER_PENALTY: u64 = SCALING_FACTOR / 2;
+
+/// Maximum number of configurable skill domains
+pub const MAX_SKILL_DOMAINS: usize = 8;
+
+/// Maximum length of a skill domain name
+pub const MAX_DOMAIN_NAME_LEN: usize = 32;
+
+/// Number of entries kept in a member's reputation ledger (ring buffer)
+pub const LEDGER_CAPACITY: usize = 32;
+
+/// Self check-in opens this long before the event start and closes when the event ends (2 hours)
+pub const CHECK_IN_WINDOW: i64 = 2 * 3600;
+
+/// Maximum lifetime of an organizer-signed check-in code (2 minutes)
+pub const CHECK_IN_CODE_TTL: i64 = 120;
+
+/// Maximum number of times a member can re-register for the same event
+pub const MAX_REREGISTRATIONS: u8 = 2;
+
+/// Default penalty-free withdrawal period after an event is rescheduled: 48h
+pub const DEFAULT_RESCHEDULE_GRACE_PERIOD: i64 = 2 * SLOT_DURATION;
+
+/// Default time after an event ends during which attendance can still be recorded: 24h
+pub const DEFAULT_ATTENDANCE_GRACE_PERIOD: i64 = SLOT_DURATION;
+
+/// Basis points denominator for category multipliers (10_000 = 1x)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum category multiplier: 5x
+pub const MAX_MULTIPLIER_BPS: u16 = 50_000;
+
+/// Default number of distinct attendees needed to attest a member present
+pub const DEFAULT_ATTESTATION_THRESHOLD: u8 = 2;
+
+/// Presence penalty for attesting a member later shown absent
+pub const FALSE_ATTESTATION_PENALTY: u64 = GHOSTING_PENALTY;
+
+/// Default time after finalization during which attendance can be disputed: 7 days
+pub const DEFAULT_DISPUTE_WINDOW: i64 = 7 * SLOT_DURATION;
+
+/// Maximum excused absence quota (size of the per-member history)
+pub const MAX_EXCUSED_ABSENCES: usize = 8;
+
+/// Default number of excused absences per rolling period
+pub const DEFAULT_EXCUSED_ABSENCE_QUOTA: u8 = 2;
+
+/// Default rolling period of the excused absence quota: 30 days
+pub const DEFAULT_EXCUSED_ABSENCE_PERIOD: i64 = 30 * SLOT_DURATION;
+
+/// Default treasury spend above which a Critical proposal is required: 10 SOL
+pub const DEFAULT_CRITICAL_SPEND_THRESHOLD: u64 = 10_000_000_000;
+
+/// Number of attendance streak bonus tiers
+pub const STREAK_TIERS: usize = 3;
+
+/// Number of SPL mints that can have their own critical spend threshold
+pub const TOKEN_SPEND_THRESHOLDS: usize = 4;
+
+/// Maximum number of co-organizers per event
+pub const MAX_CO_ORGANIZERS: usize = 4;
+
+/// Maximum number of members on an event's waitlist
+pub const MAX_WAITLIST: usize = 32;
+
+/// Maximum number of members in one `record_attendance_batch` call
+/// Each member takes 3 accounts; transactions can lock at most 64 accounts
+/// and need an address lookup table to fit this many
+pub const MAX_ATTENDANCE_BATCH: usize = 16;
+
+/// Domain separator prefixed to organizer-signed check-in messages
+pub const CHECK_IN_PREFIX: &[u8] = b"DAO_CHECK_IN";
 
 // ============================================================================
 // PROGRAM
@@ -54,12 +149,26 @@ pub mod dao {
         state.genesis_count = 0;
         state.event_counter = 0;
         state.proposal_counter = 0;
+        state.round_counter = 0;
+        state.series_counter = 0;
+        state.bounty_counter = 0;
+        state.distribution_counter = 0;
+        state.config = DaoConfig::default();
         state.bump = ctx.bumps.state;
         
         msg!("DAO initialized by: {:?}", state.authority);
         Ok(())
     }
 
+    /// Update the DAO configuration (DAO authority only)
+    pub fn update_config(ctx: Context<UpdateConfig>, config: DaoConfig) -> Result<()> {
+        config.validate()?;
+        ctx.accounts.state.config = config;
+
+        msg!("DAO configuration updated");
+        Ok(())
+    }
+
     /// Add a genesis member (maximum 3 allowed)
     pub fn add_genesis_member(ctx: Context<AddGenesisMember>) -> Result<()> {
         let state = &mut ctx.accounts.state;
@@ -77,8 +186,17 @@ pub mod dao {
         member.is_active = true;
         member.is_genesis = true;
         member.joined_at = Clock::get()?.unix_timestamp;
+        member.excused_absences = [0; MAX_EXCUSED_ABSENCES];
+        member.current_streak = 0;
+        member.longest_streak = 0;
         member.bump = ctx.bumps.member;
 
+        let ledger = &mut ctx.accounts.ledger;
+        ledger.member = member.authority;
+        ledger.bump = ctx.bumps.ledger;
+        ledger.record(member.joined_at, None, ReputationReason::Genesis, ScoreKind::Presence, GENESIS_PRESENCE as i64);
+        ledger.record(member.joined_at, None, ReputationReason::Genesis, ScoreKind::Competence, GENESIS_COMPETENCE as i64);
+
         // Update global state
         state.total_presence = state.total_presence.checked_add(GENESIS_PRESENCE)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
@@ -97,7 +215,10 @@ pub mod dao {
     pub fn create_event(
         ctx: Context<CreateEvent>,
         start_time: i64,
+        end_time: i64,
         description: String,
+        max_attendees: Option<u32>,
+        category: EventCategory,
     ) -> Result<()> {
         let state = &mut ctx.accounts.state;
         
@@ -109,25 +230,74 @@ pub mod dao {
 
         let current_time = Clock::get()?.unix_timestamp;
         require!(start_time > current_time, ErrorCode::InvalidEventTime);
+        require!(end_time > start_time, ErrorCode::InvalidEventTime);
+        require!(max_attendees != Some(0), ErrorCode::InvalidCapacity);
 
         let event = &mut ctx.accounts.event;
         event.id = state.event_counter;
         event.creator = ctx.accounts.creator.key();
         event.start_time = start_time;
+        event.end_time = end_time;
         event.description = description;
         event.is_finalized = false;
+        event.finalized_at = 0;
         event.registered_count = 0;
         event.attended_count = 0;
+        event.registration_seq = 0;
+        event.sweep_cursor = 0;
+        event.max_attendees = max_attendees;
+        event.waitlist = Vec::new();
+        event.is_cancelled = false;
+        event.penalty_free_until = 0;
+        event.series_id = None;
+        event.co_organizers = Vec::new();
+        event.category = category;
+        event.schedule = state.config.schedule(category);
+        event.streak_tiers = state.config.streak_tiers;
+        event.deposit_amount = 0;
         event.bump = ctx.bumps.event;
 
         state.event_counter = state.event_counter.checked_add(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-        msg!("Event {} created, starts at: {}", event.id, start_time);
+        msg!("Event {} created, runs from {} to {}", event.id, start_time, end_time);
+        Ok(())
+    }
+
+    /// Require a refundable SOL deposit to register for an event (organizers only)
+    /// Must be set before the first registration; deposits are escrowed in
+    /// the event vault until `withdraw_from_event` or `settle_event_deposit`
+    pub fn open_event_vault(ctx: Context<OpenEventVault>, deposit_amount: u64) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        require!(deposit_amount > 0, ErrorCode::InvalidAmount);
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+        require!(event.registration_seq == 0, ErrorCode::RegistrationsAlreadyOpen);
+        event.deposit_amount = deposit_amount;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.event_id = event.id;
+        vault.escrowed = 0;
+        vault.total_refunded = 0;
+        vault.total_forfeited = 0;
+        vault.bump = ctx.bumps.vault;
+
+        msg!("Event {} requires a deposit of {} lamports", event.id, deposit_amount);
         Ok(())
     }
 
-    /// Register for an event (with late penalty if < 24h before)
+    /// Register for an event (with late penalty inside the category late window)
+    /// When the event is full, the member joins the waitlist instead
+    /// Escrows the event deposit, if any, in the event vault
     pub fn register_for_event(ctx: Context<RegisterForEvent>) -> Result<()> {
         let state = &mut ctx.accounts.state;
         
@@ -139,6 +309,7 @@ pub mod dao {
 
         let event = &mut ctx.accounts.event;
         require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
 
         let current_time = Clock::get()?.unix_timestamp;
         require!(current_time < event.start_time, ErrorCode::EventAlreadyStarted);
@@ -146,35 +317,71 @@ pub mod dao {
         let registration = &mut ctx.accounts.registration;
         let member = &mut ctx.accounts.member;
 
-        // Check if registering late (< 24h before event)
-        let time_until_event = event.start_time.checked_sub(current_time)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-        
-        if time_until_event < SLOT_DURATION {
-            // Late registration penalty
-            let penalty = LATE_PENALTY.min(member.presence_score);
-            member.presence_score = member.presence_score.checked_sub(penalty)
-                .ok_or(ErrorCode::SlashingOverflow)?;
-            state.total_presence = state.total_presence.checked_sub(penalty)
-                .ok_or(ErrorCode::SlashingOverflow)?;
-            msg!("Late registration penalty applied: -{}", penalty);
+        let is_full = event.is_full();
+        if is_full {
+            require!(event.waitlist.len() < MAX_WAITLIST, ErrorCode::WaitlistFull);
+            event.waitlist.push(member.authority);
+        } else {
+            // Late registration penalty (inside the category late window)
+            let config = state.config;
+            charge_late_penalty(
+                &mut state.total_presence,
+                &config,
+                event,
+                member,
+                &mut ctx.accounts.ledger,
+                ReputationReason::LateRegistration,
+                current_time,
+            )?;
+            event.registered_count = event.registered_count.checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
         }
 
         registration.member = member.authority;
         registration.event_id = event.id;
-        registration.is_registered = true;
+        registration.is_registered = !is_full;
+        registration.is_waitlisted = is_full;
         registration.has_attended = false;
+        registration.attendance_status = AttendanceStatus::Unrecorded;
+        registration.attendance_delta = 0;
         registration.registered_at = current_time;
+        registration.index = event.registration_seq;
+        registration.reregister_count = 0;
+        registration.attestation_count = 0;
+        registration.is_disputed = false;
+        registration.dispute_proposal_id = None;
+        registration.absence_confirmed = false;
+        registration.excuse_status = ExcuseStatus::NotRequested;
+        registration.broken_streak = 0;
+        registration.deposit = 0;
         registration.bump = ctx.bumps.registration;
 
-        event.registered_count = event.registered_count.checked_add(1)
+        escrow_deposit(
+            event.deposit_amount,
+            registration,
+            ctx.accounts.vault.as_mut(),
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
+
+        event.registration_seq = event.registration_seq.checked_add(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-        msg!("Member {:?} registered for event {}", member.authority, event.id);
+        if is_full {
+            msg!("Member {:?} waitlisted for event {} (position {})", member.authority, event.id, event.waitlist.len());
+        } else {
+            msg!("Member {:?} registered for event {}", member.authority, event.id);
+        }
         Ok(())
     }
 
-    /// Withdraw from an event (with late penalty if < 24h before)
+    /// Withdraw from an event (with late penalty inside the category late window)
+    /// Frees the seat for the first waitlisted member, whose registration
+    /// must be passed as `promoted_registration` when the waitlist is not empty
+    /// Withdrawing is penalty-free from a cancelled event, or for a grace
+    /// period after the event was rescheduled
+    /// The deposit, if any, is refunded, or forfeited to the treasury when
+    /// withdrawing inside the late window
     pub fn withdraw_from_event(ctx: Context<WithdrawFromEvent>) -> Result<()> {
         let state = &mut ctx.accounts.state;
         
@@ -193,38 +400,80 @@ pub mod dao {
         let registration = &mut ctx.accounts.registration;
         let member = &mut ctx.accounts.member;
 
-        require!(registration.is_registered, ErrorCode::NotRegistered);
+        require!(
+            registration.is_registered || registration.is_waitlisted,
+            ErrorCode::NotRegistered
+        );
+        // Members who already checked in keep their attendance
+        require!(
+            registration.attendance_status == AttendanceStatus::Unrecorded,
+            ErrorCode::AttendanceAlreadyRecorded
+        );
 
-        // Check if withdrawing late (< 24h before event)
-        let time_until_event = event.start_time.checked_sub(current_time)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-        
-        if time_until_event < SLOT_DURATION {
-            // Late withdrawal penalty
-            let penalty = LATE_PENALTY.min(member.presence_score);
-            member.presence_score = member.presence_score.checked_sub(penalty)
-                .ok_or(ErrorCode::SlashingOverflow)?;
-            state.total_presence = state.total_presence.checked_sub(penalty)
-                .ok_or(ErrorCode::SlashingOverflow)?;
-            msg!("Late withdrawal penalty applied: -{}", penalty);
+        // Leaving the waitlist is free
+        if registration.is_waitlisted {
+            event.waitlist.retain(|wallet| *wallet != member.authority);
+            registration.is_waitlisted = false;
+            if registration.deposit > 0 {
+                let vault = ctx.accounts.vault.as_mut().ok_or(ErrorCode::EventVaultRequired)?;
+                refund_deposit(registration, vault, &ctx.accounts.authority.to_account_info())?;
+            }
+            msg!("Member {:?} left the waitlist of event {}", member.authority, event.id);
+            return Ok(());
         }
 
-        registration.is_registered = false;
+        // Late withdrawal penalty (inside the category late window)
+        let penalty_applies = event.withdrawal_penalized(current_time);
+        if penalty_applies {
+            let config = state.config;
+            charge_late_penalty(
+                &mut state.total_presence,
+                &config,
+                event,
+                member,
+                &mut ctx.accounts.ledger,
+                ReputationReason::LateWithdrawal,
+                current_time,
+            )?;
+        }
+
+        registration.record_withdrawal();
         event.registered_count = event.registered_count.checked_sub(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
 
+        if registration.deposit > 0 {
+            let vault = ctx.accounts.vault.as_mut().ok_or(ErrorCode::EventVaultRequired)?;
+            if penalty_applies && event.in_late_window(current_time)? {
+                let treasury = ctx.accounts.treasury.as_mut().ok_or(ErrorCode::TreasuryRequired)?;
+                forfeit_deposit(registration, vault, treasury)?;
+            } else {
+                refund_deposit(registration, vault, &ctx.accounts.authority.to_account_info())?;
+            }
+        }
+
         msg!("Member {:?} withdrew from event {}", member.authority, event.id);
+
+        if event.is_cancelled {
+            return Ok(());
+        }
+
+        // Promote the first waitlisted member, without late penalty
+        if !event.waitlist.is_empty() {
+            let promoted = ctx.accounts.promoted_registration.as_mut()
+                .ok_or(ErrorCode::WaitlistPromotionRequired)?;
+            event.promote_from_waitlist(promoted, current_time)?;
+
+            msg!("Member {:?} promoted from the waitlist of event {}", promoted.member, event.id);
+        }
         Ok(())
     }
 
-    /// Record attendance for a member at an event
-    /// Called by event organizer after the event
-    pub fn record_attendance(
-        ctx: Context<RecordAttendance>,
-        was_present: bool,
-    ) -> Result<()> {
+    /// Register again for an event after withdrawing, reusing the existing
+    /// registration record (with late penalty inside the category late window)
+    /// Escrows the event deposit again, if any
+    pub fn reregister_for_event(ctx: Context<ReregisterForEvent>) -> Result<()> {
         let state = &mut ctx.accounts.state;
-        
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
@@ -232,465 +481,3623 @@ pub mod dao {
         );
 
         let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < event.start_time, ErrorCode::EventAlreadyStarted);
+
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
         let registration = &mut ctx.accounts.registration;
         let member = &mut ctx.accounts.member;
 
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(current_time >= event.start_time, ErrorCode::EventNotStartedYet);
+        registration.record_reregistration(current_time)?;
 
-        let was_registered = registration.is_registered;
+        escrow_deposit(
+            event.deposit_amount,
+            registration,
+            ctx.accounts.vault.as_mut(),
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
 
-        match (was_registered, was_present) {
-            // Registered and present: reward
-            (true, true) => {
-                member.presence_score = member.presence_score.checked_add(ATTENDANCE_REWARD)
-                    .ok_or(ErrorCode::ArithmeticOverflow)?;
-                state.total_presence = state.total_presence.checked_add(ATTENDANCE_REWARD)
-                    .ok_or(ErrorCode::ArithmeticOverflow)?;
-                registration.has_attended = true;
-                event.attended_count = event.attended_count.checked_add(1)
-                    .ok_or(ErrorCode::ArithmeticOverflow)?;
-                msg!("Attendance recorded: +{} presence", ATTENDANCE_REWARD);
-            }
-            // Registered but absent (ghosting): heavy penalty
-            (true, false) => {
-                let penalty = GHOSTING_PENALTY.min(member.presence_score);
-                member.presence_score = member.presence_score.checked_sub(penalty)
-                    .ok_or(ErrorCode::SlashingOverflow)?;
-                state.total_presence = state.total_presence.checked_sub(penalty)
-                    .ok_or(ErrorCode::SlashingOverflow)?;
-                msg!("Ghosting penalty applied: -{}", penalty);
-            }
-            // Not registered but present (oubli): penalty
-            (false, true) => {
-                let penalty = OUBLI_PENALTY.min(member.presence_score);
-                member.presence_score = member.presence_score.checked_sub(penalty)
-                    .ok_or(ErrorCode::SlashingOverflow)?;
-                state.total_presence = state.total_presence.checked_sub(penalty)
-                    .ok_or(ErrorCode::SlashingOverflow)?;
-                registration.has_attended = true;
-                event.attended_count = event.attended_count.checked_add(1)
-                    .ok_or(ErrorCode::ArithmeticOverflow)?;
-                msg!("Oubli penalty applied: -{}", penalty);
-            }
-            // Not registered and not present: nothing happens
-            (false, false) => {
-                msg!("No action needed: not registered and not present");
-            }
+        if event.is_full() {
+            require!(event.waitlist.len() < MAX_WAITLIST, ErrorCode::WaitlistFull);
+            event.waitlist.push(member.authority);
+            registration.is_waitlisted = true;
+            msg!("Member {:?} waitlisted for event {} (position {})", member.authority, event.id, event.waitlist.len());
+            return Ok(());
         }
 
-        Ok(())
-    }
-
-    /// Finalize an event (no more attendance can be recorded)
-    pub fn finalize_event(ctx: Context<FinalizeEvent>) -> Result<()> {
-        let state = &ctx.accounts.state;
-        
-        // Kill switch check
-        require!(
-            state.active_members >= MIN_QUORUM,
-            ErrorCode::DaoShutdown
-        );
+        // Late registration penalty (inside the category late window)
+        let config = state.config;
+        charge_late_penalty(
+            &mut state.total_presence,
+            &config,
+            event,
+            member,
+            &mut ctx.accounts.ledger,
+            ReputationReason::LateRegistration,
+            current_time,
+        )?;
 
-        let event = &mut ctx.accounts.event;
-        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
-        
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(current_time >= event.start_time, ErrorCode::EventNotStartedYet);
+        registration.is_registered = true;
+        event.registered_count = event.registered_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-        event.is_finalized = true;
-        msg!("Event {} finalized", event.id);
+        msg!("Member {:?} re-registered for event {}", member.authority, event.id);
         Ok(())
     }
 
-    /// Update competence score for a member (peer review)
-    pub fn update_competence(
-        ctx: Context<UpdateCompetence>,
-        score_delta: i64,
+    /// Create a recurring event series
+    /// Occurrences are spawned on demand by `spawn_series_event`
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_event_series(
+        ctx: Context<CreateEventSeries>,
+        first_start_time: i64,
+        duration: i64,
+        description: String,
+        recurrence: Recurrence,
+        end: SeriesEnd,
+        max_attendees: Option<u32>,
+        category: EventCategory,
     ) -> Result<()> {
         let state = &mut ctx.accounts.state;
-        
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
             ErrorCode::DaoShutdown
         );
 
-        let member = &mut ctx.accounts.target_member;
-        
-        if score_delta >= 0 {
-            let delta = (score_delta as u64).checked_mul(SCALING_FACTOR)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-            member.competence_score = member.competence_score.checked_add(delta)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-            state.total_competence = state.total_competence.checked_add(delta)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-        } else {
-            let delta = ((-score_delta) as u64).checked_mul(SCALING_FACTOR)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-            let actual_delta = delta.min(member.competence_score);
-            member.competence_score = member.competence_score.checked_sub(actual_delta)
-                .ok_or(ErrorCode::SlashingOverflow)?;
-            state.total_competence = state.total_competence.checked_sub(actual_delta)
-                .ok_or(ErrorCode::SlashingOverflow)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(first_start_time > current_time, ErrorCode::InvalidEventTime);
+        require!(duration > 0, ErrorCode::InvalidEventTime);
+        require!(max_attendees != Some(0), ErrorCode::InvalidCapacity);
+        match end {
+            SeriesEnd::Count(occurrences) => {
+                require!(occurrences > 0, ErrorCode::InvalidSeriesSchedule);
+            }
+            SeriesEnd::Until(until) => {
+                require!(until >= first_start_time, ErrorCode::InvalidSeriesSchedule);
+            }
         }
 
-        msg!("Competence updated for {:?}: delta={}", member.authority, score_delta);
+        let series = &mut ctx.accounts.series;
+        series.id = state.series_counter;
+        series.creator = ctx.accounts.creator.key();
+        series.description = description;
+        series.first_start_time = first_start_time;
+        series.duration = duration;
+        series.recurrence = recurrence;
+        series.end = end;
+        series.max_attendees = max_attendees;
+        series.category = category;
+        series.next_occurrence = 0;
+        series.spawned_count = 0;
+        series.bump = ctx.bumps.series;
+
+        state.series_counter = state.series_counter.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Event series {} created, first occurrence at {}", series.id, first_start_time);
         Ok(())
     }
 
-    /// Create a new proposal
-    pub fn create_proposal(
-        ctx: Context<CreateProposal>,
-        title: String,
-        description: String,
-        proposal_type: ProposalType,
-        voting_period: i64,
-    ) -> Result<()> {
+    /// Create the next upcoming occurrence of a series (permissionless crank)
+    /// Occurrences whose start time already passed are skipped
+    pub fn spawn_series_event(ctx: Context<SpawnSeriesEvent>) -> Result<()> {
         let state = &mut ctx.accounts.state;
-        
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
             ErrorCode::DaoShutdown
         );
 
-        let member = &ctx.accounts.member;
-        require!(member.is_active, ErrorCode::MemberNotActive);
-
-        // Calculate total voting power snapshot at proposal creation
-        let total_power_snapshot = calculate_total_voting_power(state);
-
         let current_time = Clock::get()?.unix_timestamp;
-        let proposal = &mut ctx.accounts.proposal;
-        
-        proposal.id = state.proposal_counter;
-        proposal.proposer = member.authority;
-        proposal.title = title;
-        proposal.description = description;
-        proposal.proposal_type = proposal_type;
-        proposal.votes_for = 0;
-        proposal.votes_against = 0;
-        proposal.total_power_snapshot = total_power_snapshot;
-        proposal.created_at = current_time;
-        proposal.voting_ends_at = current_time.checked_add(voting_period)
+        let series = &mut ctx.accounts.series;
+        let occurrence = series.next_upcoming_occurrence(current_time)?;
+        let start_time = series.occurrence_start(occurrence)?;
+        require!(!series.is_past_end(occurrence, start_time), ErrorCode::SeriesComplete);
+
+        let event = &mut ctx.accounts.event;
+        event.id = state.event_counter;
+        event.creator = series.creator;
+        event.start_time = start_time;
+        event.end_time = start_time.checked_add(series.duration)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
-        proposal.status = ProposalStatus::Active;
-        proposal.bump = ctx.bumps.proposal;
+        event.description = series.description.clone();
+        event.is_finalized = false;
+        event.finalized_at = 0;
+        event.registered_count = 0;
+        event.attended_count = 0;
+        event.registration_seq = 0;
+        event.sweep_cursor = 0;
+        event.max_attendees = series.max_attendees;
+        event.waitlist = Vec::new();
+        event.is_cancelled = false;
+        event.penalty_free_until = 0;
+        event.series_id = Some(series.id);
+        event.co_organizers = Vec::new();
+        event.category = series.category;
+        event.schedule = state.config.schedule(series.category);
+        event.streak_tiers = state.config.streak_tiers;
+        event.deposit_amount = 0;
+        event.bump = ctx.bumps.event;
 
-        state.proposal_counter = state.proposal_counter.checked_add(1)
+        series.next_occurrence = occurrence.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        series.spawned_count = series.spawned_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        state.event_counter = state.event_counter.checked_add(1)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-        msg!("Proposal {} created: {}", proposal.id, proposal.title);
+        msg!("Series {} occurrence {} spawned as event {}", series.id, occurrence, event.id);
         Ok(())
     }
 
-    /// Vote on a proposal
-    pub fn vote(ctx: Context<Vote>, support: bool) -> Result<()> {
+    /// Subscribe to a series: every new occurrence registers the member
+    pub fn subscribe_to_series(ctx: Context<SubscribeToSeries>) -> Result<()> {
         let state = &ctx.accounts.state;
-        
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
             ErrorCode::DaoShutdown
         );
 
-        let member = &ctx.accounts.member;
-        require!(member.is_active, ErrorCode::MemberNotActive);
-
-        let proposal = &mut ctx.accounts.proposal;
-        require!(
-            proposal.status == ProposalStatus::Active,
-            ErrorCode::ProposalNotActive
-        );
-
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(
-            current_time < proposal.voting_ends_at,
-            ErrorCode::VotingPeriodEnded
-        );
-
-        let vote_record = &mut ctx.accounts.vote_record;
-        require!(!vote_record.has_voted, ErrorCode::AlreadyVoted);
-
-        // Calculate voting weight
-        let voting_weight = calculate_voting_weight(member, state);
-        require!(voting_weight > 0, ErrorCode::InsufficientReputation);
-
-        if support {
-            proposal.votes_for = proposal.votes_for.checked_add(voting_weight)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-        } else {
-            proposal.votes_against = proposal.votes_against.checked_add(voting_weight)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-        }
+        let subscription = &mut ctx.accounts.subscription;
+        subscription.member = ctx.accounts.authority.key();
+        subscription.series_id = ctx.accounts.series.id;
+        subscription.subscribed_at = Clock::get()?.unix_timestamp;
+        subscription.bump = ctx.bumps.subscription;
 
-        vote_record.voter = member.authority;
-        vote_record.proposal_id = proposal.id;
-        vote_record.support = support;
-        vote_record.weight = voting_weight;
-        vote_record.has_voted = true;
-        vote_record.bump = ctx.bumps.vote_record;
+        msg!("Member {:?} subscribed to series {}", subscription.member, subscription.series_id);
+        Ok(())
+    }
 
+    /// Unsubscribe from a series (existing registrations are kept)
+    pub fn unsubscribe_from_series(ctx: Context<UnsubscribeFromSeries>) -> Result<()> {
         msg!(
-            "Vote recorded: {:?} voted {} with weight {}",
-            member.authority,
-            if support { "FOR" } else { "AGAINST" },
-            voting_weight
+            "Member {:?} unsubscribed from series {}",
+            ctx.accounts.subscription.member,
+            ctx.accounts.subscription.series_id
         );
         Ok(())
     }
 
-    /// Execute/finalize a proposal after voting period
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    /// Register a subscriber for an occurrence of their series (permissionless crank)
+    /// Auto-registration carries no late penalty, so only subscriptions made
+    /// before the occurrence's late window opened apply; the member joins the
+    /// waitlist when the occurrence is full and can withdraw under the
+    /// normal late rules
+    pub fn apply_series_subscription(ctx: Context<ApplySeriesSubscription>) -> Result<()> {
         let state = &ctx.accounts.state;
-        
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
             ErrorCode::DaoShutdown
         );
 
-        let proposal = &mut ctx.accounts.proposal;
-        require!(
-            proposal.status == ProposalStatus::Active,
-            ErrorCode::ProposalNotActive
-        );
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+        // The subscriber doesn't sign here, so cannot pay a deposit
+        require!(event.deposit_amount == 0, ErrorCode::DepositRequired);
 
         let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < event.start_time, ErrorCode::EventAlreadyStarted);
         require!(
-            current_time >= proposal.voting_ends_at,
-            ErrorCode::VotingPeriodNotEnded
+            ctx.accounts.subscription.predates_late_window(event)?,
+            ErrorCode::SubscriptionTooLate
         );
 
-        // Determine if proposal passed based on type
-        let passed = match proposal.proposal_type {
-            // Critical proposals need absolute majority (> 50% of total power)
-            ProposalType::Critical => {
-                let threshold = proposal.total_power_snapshot / 2;
-                proposal.votes_for > threshold
-            }
-            // Operational proposals need relative majority (for > against)
-            ProposalType::Operational => {
-                proposal.votes_for > proposal.votes_against
-            }
-        };
-
-        proposal.status = if passed {
-            ProposalStatus::Passed
+        let wallet = ctx.accounts.subscription.member;
+        let is_full = event.is_full();
+        if is_full {
+            require!(event.waitlist.len() < MAX_WAITLIST, ErrorCode::WaitlistFull);
+            event.waitlist.push(wallet);
         } else {
-            ProposalStatus::Rejected
-        };
+            event.registered_count = event.registered_count.checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
 
-        msg!(
-            "Proposal {} {}: for={}, against={}, threshold={}",
-            proposal.id,
-            if passed { "PASSED" } else { "REJECTED" },
-            proposal.votes_for,
-            proposal.votes_against,
-            proposal.total_power_snapshot / 2
-        );
+        let registration = &mut ctx.accounts.registration;
+        registration.member = wallet;
+        registration.event_id = event.id;
+        registration.is_registered = !is_full;
+        registration.is_waitlisted = is_full;
+        registration.has_attended = false;
+        registration.attendance_status = AttendanceStatus::Unrecorded;
+        registration.attendance_delta = 0;
+        registration.registered_at = current_time;
+        registration.index = event.registration_seq;
+        registration.reregister_count = 0;
+        registration.attestation_count = 0;
+        registration.is_disputed = false;
+        registration.dispute_proposal_id = None;
+        registration.absence_confirmed = false;
+        registration.excuse_status = ExcuseStatus::NotRequested;
+        registration.broken_streak = 0;
+        registration.deposit = 0;
+        registration.bump = ctx.bumps.registration;
+
+        event.registration_seq = event.registration_seq.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Subscriber {:?} auto-registered for event {}", wallet, event.id);
         Ok(())
     }
 
-    /// Ban a member (requires passed Critical proposal)
-    pub fn ban_member(ctx: Context<BanMember>) -> Result<()> {
-        let state = &mut ctx.accounts.state;
-        
+    /// Read-only: late penalty the member would pay by registering
+    /// (`withdrawal = false`) or withdrawing (`withdrawal = true`) now
+    /// Returned through the transaction return data, for simulation by clients
+    pub fn preview_late_penalty(ctx: Context<PreviewLatePenalty>, withdrawal: bool) -> Result<u64> {
+        let event = &ctx.accounts.event;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        if withdrawal && !event.withdrawal_penalized(current_time) {
+            return Ok(0);
+        }
+
+        let time_until_event = event.start_time.checked_sub(current_time)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let penalty = late_penalty_amount(&ctx.accounts.state.config, &event.schedule, time_until_event)?;
+
+        // Penalties are capped at the member's current presence
+        Ok(penalty.min(ctx.accounts.member.presence_score))
+    }
+
+    /// Declare an excused absence for a registered event, before it starts
+    /// Counts against the member's quota over the rolling period; when the
+    /// configuration requires it, an organizer must approve the excuse
+    /// Excused members are not penalized if absent
+    pub fn declare_excused_absence(ctx: Context<DeclareExcusedAbsence>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
             ErrorCode::DaoShutdown
         );
 
-        let member = &mut ctx.accounts.target_member;
-        require!(member.is_active, ErrorCode::MemberNotActive);
+        let event = &ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
 
-        // Remove member's scores from totals
-        state.total_presence = state.total_presence.checked_sub(member.presence_score)
-            .ok_or(ErrorCode::SlashingOverflow)?;
-        state.total_competence = state.total_competence.checked_sub(member.competence_score)
-            .ok_or(ErrorCode::SlashingOverflow)?;
-        state.active_members = state.active_members.checked_sub(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < event.start_time, ErrorCode::EventAlreadyStarted);
 
-        member.is_active = false;
-        member.presence_score = 0;
-        member.competence_score = 0;
+        let registration = &mut ctx.accounts.registration;
+        require!(registration.is_registered, ErrorCode::NotRegistered);
+        require!(
+            registration.attendance_status == AttendanceStatus::Unrecorded,
+            ErrorCode::AttendanceAlreadyRecorded
+        );
+        require!(
+            registration.excuse_status == ExcuseStatus::NotRequested,
+            ErrorCode::AbsenceAlreadyDeclared
+        );
 
-        msg!("Member {:?} has been banned", member.authority);
-        
-        // Check kill switch after banning
-        if state.active_members < MIN_QUORUM {
-            msg!("WARNING: DAO is now frozen (< {} active members)", MIN_QUORUM);
+        let config = state.config;
+        let member = &mut ctx.accounts.member;
+        require!(
+            member.excused_absences_in_period(current_time, config.excused_absence_period)
+                < config.excused_absence_quota,
+            ErrorCode::ExcusedAbsenceQuotaExceeded
+        );
+
+        if config.excused_absence_requires_approval {
+            registration.excuse_status = ExcuseStatus::Pending;
+            msg!("Excused absence requested by {:?} for event {}", member.authority, event.id);
+        } else {
+            member.record_excused_absence(current_time);
+            registration.excuse_status = ExcuseStatus::Approved;
+            msg!("Excused absence declared by {:?} for event {}", member.authority, event.id);
         }
-        
         Ok(())
     }
 
-    /// Coopt a new member (requires passed Critical proposal)
-    pub fn coopt_member(ctx: Context<CooptMember>) -> Result<()> {
-        let state = &mut ctx.accounts.state;
-        
+    /// Approve a pending excused absence (event organizer)
+    pub fn approve_excused_absence(ctx: Context<ApproveExcusedAbsence>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
         // Kill switch check
         require!(
             state.active_members >= MIN_QUORUM,
             ErrorCode::DaoShutdown
         );
 
-        let member = &mut ctx.accounts.new_member;
-        
-        // New coopted members start with minimal scores
-        let initial_presence = SCALING_FACTOR; // 1 * SCALING_FACTOR
-        let initial_competence = SCALING_FACTOR; // 1 * SCALING_FACTOR
+        let event = &ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
 
-        member.authority = ctx.accounts.new_member_authority.key();
-        member.presence_score = initial_presence;
-        member.competence_score = initial_competence;
-        member.is_active = true;
-        member.is_genesis = false;
-        member.joined_at = Clock::get()?.unix_timestamp;
-        member.bump = ctx.bumps.new_member;
+        let registration = &mut ctx.accounts.registration;
+        require!(
+            registration.excuse_status == ExcuseStatus::Pending,
+            ErrorCode::NoPendingExcuse
+        );
+        require!(
+            registration.attendance_status == AttendanceStatus::Unrecorded,
+            ErrorCode::AttendanceAlreadyRecorded
+        );
 
-        // Update global state
-        state.total_presence = state.total_presence.checked_add(initial_presence)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-        state.total_competence = state.total_competence.checked_add(initial_competence)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-        state.active_members = state.active_members.checked_add(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = state.config;
+        let member = &mut ctx.accounts.member;
+        require!(
+            member.excused_absences_in_period(current_time, config.excused_absence_period)
+                < config.excused_absence_quota,
+            ErrorCode::ExcusedAbsenceQuotaExceeded
+        );
 
-        msg!("New member coopted: {:?}", member.authority);
+        member.record_excused_absence(current_time);
+        registration.excuse_status = ExcuseStatus::Approved;
+
+        msg!("Excused absence of {:?} approved for event {}", member.authority, event.id);
         Ok(())
     }
-}
 
-// ============================================================================
-// HELPER FUNCTIONS
-// ============================================================================
+    /// Record attendance for a member at an event
+    /// Called by an event organizer (creator or co-organizer) after the event;
+    /// organizers cannot record their own attendance
+    pub fn record_attendance(
+        ctx: Context<RecordAttendance>,
+        was_present: bool,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
 
-/// Calculate voting weight for a member
-/// Formula: S_member = (Pres_member/Pres_total) × (Comp_member/Comp_total)
-/// Returns scaled u128 to prevent overflow
-fn calculate_voting_weight(member: &Member, state: &State) -> u128 {
-    if state.total_presence == 0 || state.total_competence == 0 {
-        return 0;
-    }
-    
-    let p_m = member.presence_score as u128;
-    let c_m = member.competence_score as u128;
-    let p_tot = state.total_presence as u128;
-    let c_tot = state.total_competence as u128;
-    let scale = SCALING_FACTOR as u128;
+        require!(!ctx.accounts.event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!ctx.accounts.event.is_cancelled, ErrorCode::EventCancelled);
 
-    // (p_m * c_m * SCALING_FACTOR) / (p_tot * c_tot)
-    let numerator = p_m.saturating_mul(c_m).saturating_mul(scale);
-    let denominator = p_tot.saturating_mul(c_tot);
-    
-    if denominator == 0 {
-        0
-    } else {
-        numerator / denominator
-    }
-}
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= ctx.accounts.event.start_time, ErrorCode::EventNotStartedYet);
+        require!(
+            current_time <= ctx.accounts.event.recording_closes_at(state.config.attendance_grace_period)?,
+            ErrorCode::RecordingWindowClosed
+        );
 
-/// Calculate total voting power (sum of all members' weights = SCALING_FACTOR)
-fn calculate_total_voting_power(state: &State) -> u128 {
-    // By the formula, sum of all weights equals SCALING_FACTOR
-    // when everyone has non-zero scores
-    if state.total_presence == 0 || state.total_competence == 0 {
-        0
-    } else {
-        SCALING_FACTOR as u128
+        apply_attendance(
+            &mut state.total_presence,
+            &mut ctx.accounts.event,
+            &mut ctx.accounts.registration,
+            &mut ctx.accounts.member,
+            &mut ctx.accounts.ledger,
+            was_present,
+            current_time,
+        )?;
+        ctx.accounts.registration.absence_confirmed = !was_present;
+        Ok(())
     }
-}
 
-// ============================================================================
-// ACCOUNT STRUCTURES (PDAs)
-// ============================================================================
+    /// Record a member who attended without ever registering (oubli)
+    /// The registration record is created on the fly, paid by the organizer
+    pub fn record_oubli(ctx: Context<RecordOubli>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
 
-/// Global DAO state (singleton)
-#[account]
-#[derive(InitSpace)]
-pub struct State {
-    /// Authority who initialized the DAO
-    pub authority: Pubkey,
-    /// Sum of all active members' presence scores
-    pub total_presence: u64,
-    /// Sum of all active members' competence scores
-    pub total_competence: u64,
-    /// Number of currently active members
-    pub active_members: u8,
-    /// Number of genesis members added (max 3)
-    pub genesis_count: u8,
-    /// Counter for event IDs
-    pub event_counter: u64,
-    /// Counter for proposal IDs
-    pub proposal_counter: u64,
-    /// PDA bump
-    pub bump: u8,
-}
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
 
-/// Member account
-#[account]
-#[derive(InitSpace)]
-pub struct Member {
-    /// Member's wallet address
-    pub authority: Pubkey,
-    /// Presence score (scaled by SCALING_FACTOR)
-    pub presence_score: u64,
-    /// Competence score (scaled by SCALING_FACTOR)
-    pub competence_score: u64,
-    /// Whether member is currently active
-    pub is_active: bool,
-    /// Whether member is a genesis member
-    pub is_genesis: bool,
-    /// Timestamp when member joined
-    pub joined_at: i64,
-    /// PDA bump
-    pub bump: u8,
-}
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
 
-/// Event/Track session
-#[account]
-#[derive(InitSpace)]
-pub struct TrackSession {
-    /// Event ID
-    pub id: u64,
-    /// Creator of the event
-    pub creator: Pubkey,
-    /// Start time (unix timestamp)
-    pub start_time: i64,
-    /// Event description
-    #[max_len(256)]
-    pub description: String,
-    /// Whether attendance recording is finalized
-    pub is_finalized: bool,
-    /// Number of registered members
-    pub registered_count: u32,
-    /// Number of members who attended
-    pub attended_count: u32,
-    /// PDA bump
-    pub bump: u8,
-}
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= event.start_time, ErrorCode::EventNotStartedYet);
+        require!(
+            current_time <= event.recording_closes_at(state.config.attendance_grace_period)?,
+            ErrorCode::RecordingWindowClosed
+        );
 
-/// Event registration record
-#[account]
-#[derive(InitSpace)]
-pub struct EventRegistration {
-    /// Member's wallet address
-    pub member: Pubkey,
-    /// Event ID
-    pub event_id: u64,
-    /// Whether currently registered
-    pub is_registered: bool,
-    /// Whether attended the event
-    pub has_attended: bool,
-    /// Registration timestamp
-    pub registered_at: i64,
-    /// PDA bump
-    pub bump: u8,
-}
+        let registration = &mut ctx.accounts.registration;
+        registration.member = ctx.accounts.member_authority.key();
+        registration.event_id = event.id;
+        registration.is_registered = false;
+        registration.has_attended = false;
+        registration.attendance_status = AttendanceStatus::Unrecorded;
+        registration.attendance_delta = 0;
+        registration.registered_at = current_time;
+        registration.index = event.registration_seq;
+        registration.reregister_count = 0;
+        registration.attestation_count = 0;
+        registration.is_disputed = false;
+        registration.dispute_proposal_id = None;
+        registration.absence_confirmed = false;
+        registration.excuse_status = ExcuseStatus::NotRequested;
+        registration.broken_streak = 0;
+        registration.deposit = 0;
+        registration.bump = ctx.bumps.registration;
+
+        event.registration_seq = event.registration_seq.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        apply_attendance(
+            &mut state.total_presence,
+            event,
+            registration,
+            &mut ctx.accounts.member,
+            &mut ctx.accounts.ledger,
+            true,
+            current_time,
+        )
+    }
+
+    /// Correct a previously recorded attendance before the event is finalized
+    /// The previous presence delta is reversed before the new outcome is applied
+    pub fn correct_attendance(
+        ctx: Context<RecordAttendance>,
+        was_present: bool,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
+        let registration = &mut ctx.accounts.registration;
+        require!(
+            registration.attendance_status != AttendanceStatus::Unrecorded,
+            ErrorCode::AttendanceNotRecorded
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time <= event.recording_closes_at(state.config.attendance_grace_period)?,
+            ErrorCode::RecordingWindowClosed
+        );
+
+        let member = &mut ctx.accounts.member;
+        let ledger = &mut ctx.accounts.ledger;
+
+        revert_attendance(&mut state.total_presence, event, registration, member, ledger, current_time)?;
+        apply_attendance(&mut state.total_presence, event, registration, member, ledger, was_present, current_time)?;
+        registration.absence_confirmed = !was_present;
+        Ok(())
+    }
+
+    /// Record attendance for several members at once
+    /// An organizer cannot be part of their own batch
+    /// `remaining_accounts` holds one (Member, EventRegistration, ReputationLedger)
+    /// triplet per entry, in the same order as `entries`
+    pub fn record_attendance_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordAttendanceBatch<'info>>,
+        entries: Vec<AttendanceEntry>,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        require!(
+            !entries.is_empty() && entries.len() <= MAX_ATTENDANCE_BATCH,
+            ErrorCode::InvalidBatchSize
+        );
+        require!(
+            ctx.remaining_accounts.len() == entries.len() * 3,
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= event.start_time, ErrorCode::EventNotStartedYet);
+        require!(
+            current_time <= event.recording_closes_at(state.config.attendance_grace_period)?,
+            ErrorCode::RecordingWindowClosed
+        );
+
+        // Accumulate locally and write the State total once at the end
+        let mut total_presence = state.total_presence;
+
+        let organizer = ctx.accounts.organizer.key();
+        for (entry, accounts) in entries.iter().zip(ctx.remaining_accounts.chunks(3)) {
+            require_keys_neq!(entry.wallet, organizer, ErrorCode::OrganizerSelfAttendance);
+            let mut member = load_member(&accounts[0], &entry.wallet)?;
+            let mut registration = load_registration(&accounts[1], event.id)?;
+            require_keys_eq!(registration.member, entry.wallet, ErrorCode::InvalidRemainingAccounts);
+            let mut ledger = load_ledger(&accounts[2], &entry.wallet)?;
+
+            apply_attendance(
+                &mut total_presence,
+                event,
+                &mut registration,
+                &mut member,
+                &mut ledger,
+                entry.was_present,
+                current_time,
+            )?;
+            registration.absence_confirmed = !entry.was_present;
+
+            member.exit(&crate::ID)?;
+            registration.exit(&crate::ID)?;
+            ledger.exit(&crate::ID)?;
+        }
+
+        state.total_presence = total_presence;
+
+        msg!("Attendance batch of {} recorded for event {}", entries.len(), event.id);
+        Ok(())
+    }
+
+    /// Apply the ghosting penalty to registered members whose attendance was
+    /// never recorded, once the event is finalized or its recording window
+    /// has closed (permissionless crank)
+    /// `remaining_accounts` holds (EventRegistration, Member, ReputationLedger)
+    /// triplets for consecutive registration indexes starting at the event's
+    /// sweep cursor, so the sweep can finish over several transactions
+    pub fn sweep_ghosts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepGhosts<'info>>,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let event = &mut ctx.accounts.event;
+        if !event.is_finalized {
+            require!(!event.is_cancelled, ErrorCode::EventCancelled);
+            require!(
+                current_time > event.recording_closes_at(state.config.attendance_grace_period)?,
+                ErrorCode::EventNotFinalized
+            );
+            event.is_finalized = true;
+            event.finalized_at = current_time;
+            msg!("Event {} auto-finalized", event.id);
+        }
+        require!(event.sweep_cursor < event.registration_seq, ErrorCode::SweepComplete);
+
+        let count = ctx.remaining_accounts.len() / 3;
+        require!(
+            count > 0 && count <= MAX_ATTENDANCE_BATCH && ctx.remaining_accounts.len() % 3 == 0,
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        // Accumulate locally and write the State total once at the end
+        let mut total_presence = state.total_presence;
+
+        for accounts in ctx.remaining_accounts.chunks(3) {
+            let mut registration = load_registration(&accounts[0], event.id)?;
+            let sweep_complete = event.advance_sweep(registration.index)?;
+
+            if registration.awaits_ghosting() {
+                let wallet = registration.member;
+                let mut member = load_member(&accounts[1], &wallet)?;
+                let mut ledger = load_ledger(&accounts[2], &wallet)?;
+
+                apply_attendance(
+                    &mut total_presence,
+                    event,
+                    &mut registration,
+                    &mut member,
+                    &mut ledger,
+                    false,
+                    current_time,
+                )?;
+
+                member.exit(&crate::ID)?;
+                registration.exit(&crate::ID)?;
+                ledger.exit(&crate::ID)?;
+            }
+
+            if sweep_complete {
+                break;
+            }
+        }
+
+        state.total_presence = total_presence;
+
+        msg!("Ghost sweep for event {}: {}/{}", event.id, event.sweep_cursor, event.registration_seq);
+        Ok(())
+    }
+
+    /// Self check-in with a short-lived code signed by an event organizer
+    /// other than the member checking in
+    /// Open from CHECK_IN_WINDOW before the start until the event ends
+    /// The transaction must include, right before this instruction, an
+    /// ed25519 signature-verification instruction over the check-in message
+    /// `CHECK_IN_PREFIX || event_id (u64 LE) || expires_at (i64 LE)`
+    pub fn self_check_in(ctx: Context<SelfCheckIn>, expires_at: i64) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(event.check_in_open(current_time)?, ErrorCode::CheckInClosed);
+        require!(check_in_code_valid(current_time, expires_at), ErrorCode::CheckInCodeExpired);
+
+        let mut message = Vec::with_capacity(CHECK_IN_PREFIX.len() + 16);
+        message.extend_from_slice(CHECK_IN_PREFIX);
+        message.extend_from_slice(&event.id.to_le_bytes());
+        message.extend_from_slice(&expires_at.to_le_bytes());
+        let signer = verify_organizer_signature(&ctx.accounts.instructions, event, &message)?;
+
+        let registration = &mut ctx.accounts.registration;
+        require_keys_neq!(signer, registration.member, ErrorCode::OrganizerSelfAttendance);
+        require!(
+            registration.attendance_status == AttendanceStatus::Unrecorded,
+            ErrorCode::AlreadyCheckedIn
+        );
+
+        apply_attendance(
+            &mut state.total_presence,
+            event,
+            registration,
+            &mut ctx.accounts.member,
+            &mut ctx.accounts.ledger,
+            true,
+            current_time,
+        )
+    }
+
+    /// Attest that another registered member attended (peer-witnessed attendance)
+    /// The witness must have been recorded present at the event; the subject
+    /// is recorded present once `attestation_threshold` distinct witnesses attested
+    pub fn attest_attendance(ctx: Context<AttestAttendance>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= event.start_time, ErrorCode::EventNotStartedYet);
+        require!(
+            current_time <= event.recording_closes_at(state.config.attendance_grace_period)?,
+            ErrorCode::RecordingWindowClosed
+        );
+
+        let registration = &mut ctx.accounts.subject_registration;
+        require!(registration.is_registered, ErrorCode::NotRegistered);
+        require!(
+            registration.attendance_status == AttendanceStatus::Unrecorded,
+            ErrorCode::AttendanceAlreadyRecorded
+        );
+
+        let attestation = &mut ctx.accounts.attestation;
+        attestation.event_id = event.id;
+        attestation.witness = ctx.accounts.witness.key();
+        attestation.subject = registration.member;
+        attestation.attested_at = current_time;
+        attestation.is_slashed = false;
+        attestation.bump = ctx.bumps.attestation;
+
+        registration.attestation_count = registration.attestation_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!(
+            "{:?} attested {:?} at event {} ({}/{})",
+            attestation.witness,
+            attestation.subject,
+            event.id,
+            registration.attestation_count,
+            state.config.attestation_threshold
+        );
+
+        if registration.attestation_count >= state.config.attestation_threshold {
+            apply_attendance(
+                &mut state.total_presence,
+                event,
+                registration,
+                &mut ctx.accounts.subject_member,
+                &mut ctx.accounts.subject_ledger,
+                true,
+                current_time,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Penalize a witness who attested a member whose absence was confirmed,
+    /// once the event is finalized (permissionless crank)
+    /// The absence counts as confirmed when an organizer recorded it, and
+    /// when disputed, only once the dispute failed (`dispute_proposal`);
+    /// a ghosting applied by the sweep alone never slashes witnesses
+    pub fn slash_false_attestation(ctx: Context<SlashFalseAttestation>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &ctx.accounts.event;
+        require!(event.is_finalized, ErrorCode::EventNotFinalized);
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.subject_registration
+                .is_confirmed_absent(ctx.accounts.dispute_proposal.as_deref(), current_time),
+            ErrorCode::AttestationNotDisproved
+        );
+
+        let attestation = &mut ctx.accounts.attestation;
+        require!(!attestation.is_slashed, ErrorCode::AttestationAlreadySlashed);
+        attestation.is_slashed = true;
+
+        let penalty = event.schedule.scaled_penalty(FALSE_ATTESTATION_PENALTY)?;
+        let applied = adjust_presence(
+            &mut ctx.accounts.witness_member,
+            &mut state.total_presence,
+            -(penalty as i64),
+        )?;
+        ctx.accounts.witness_ledger.record(
+            current_time,
+            Some(event.id),
+            ReputationReason::FalseAttestation,
+            ScoreKind::Presence,
+            applied,
+        );
+
+        msg!("False attestation by {:?} penalized: {}", attestation.witness, applied);
+        Ok(())
+    }
+
+    /// Dispute a ghosting record after the event was finalized
+    /// Opens an Operational proposal naming the event and member; if it passes,
+    /// `resolve_attendance_dispute` reverses the penalty and applies the reward
+    pub fn open_attendance_dispute(
+        ctx: Context<OpenAttendanceDispute>,
+        description: String,
+        voting_period: i64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &ctx.accounts.event;
+        require!(event.is_finalized, ErrorCode::EventNotFinalized);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let dispute_closes_at = event.finalized_at.checked_add(state.config.dispute_window)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(current_time <= dispute_closes_at, ErrorCode::DisputeWindowClosed);
+
+        let registration = &mut ctx.accounts.registration;
+        require!(
+            registration.attendance_status == AttendanceStatus::Ghosted,
+            ErrorCode::NotGhosted
+        );
+        require!(!registration.is_disputed, ErrorCode::AlreadyDisputed);
+        registration.is_disputed = true;
+        registration.dispute_proposal_id = Some(state.proposal_counter);
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.id = state.proposal_counter;
+        proposal.proposer = registration.member;
+        proposal.title = format!("Attendance dispute: event {}", event.id);
+        proposal.description = description;
+        proposal.proposal_type = ProposalType::Operational;
+        proposal.domain = None;
+        proposal.action = Some(ProposalAction::AttendanceDispute {
+            event_id: event.id,
+            member: registration.member,
+        });
+        proposal.executed = false;
+        proposal.votes_for = 0;
+        proposal.votes_against = 0;
+        proposal.total_power_snapshot = calculate_total_voting_power(state);
+        proposal.created_at = current_time;
+        proposal.voting_ends_at = current_time.checked_add(voting_period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        proposal.status = ProposalStatus::Active;
+        proposal.bump = ctx.bumps.proposal;
+
+        state.proposal_counter = state.proposal_counter.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Attendance dispute {} opened for event {}", proposal.id, event.id);
+        Ok(())
+    }
+
+    /// Apply a passed attendance dispute: reverse the ghosting penalty and
+    /// record the member present (permissionless)
+    pub fn resolve_attendance_dispute(ctx: Context<ResolveAttendanceDispute>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        let registration = &mut ctx.accounts.registration;
+        consume_proposal_action(
+            &mut ctx.accounts.proposal,
+            ProposalAction::AttendanceDispute { event_id: event.id, member: registration.member },
+        )?;
+        require!(
+            registration.attendance_status == AttendanceStatus::Ghosted,
+            ErrorCode::NotGhosted
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let member = &mut ctx.accounts.member;
+        let ledger = &mut ctx.accounts.ledger;
+
+        revert_attendance(&mut state.total_presence, event, registration, member, ledger, current_time)?;
+        apply_attendance(&mut state.total_presence, event, registration, member, ledger, true, current_time)?;
+
+        msg!("Attendance dispute resolved for {:?} at event {}", registration.member, event.id);
+        Ok(())
+    }
+
+    /// Add a co-organizer sharing the organizer privileges (creator only)
+    pub fn add_co_organizer(ctx: Context<AddCoOrganizer>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+
+        let co_organizer = ctx.accounts.co_organizer.key();
+        require!(!event.is_organizer(&co_organizer), ErrorCode::AlreadyOrganizer);
+        require!(event.co_organizers.len() < MAX_CO_ORGANIZERS, ErrorCode::TooManyCoOrganizers);
+        event.co_organizers.push(co_organizer);
+
+        msg!("Co-organizer {:?} added to event {}", co_organizer, event.id);
+        Ok(())
+    }
+
+    /// Remove a co-organizer (creator only)
+    pub fn remove_co_organizer(ctx: Context<RemoveCoOrganizer>, co_organizer: Pubkey) -> Result<()> {
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+
+        let position = event.co_organizers.iter()
+            .position(|wallet| *wallet == co_organizer)
+            .ok_or(ErrorCode::NotCoOrganizer)?;
+        event.co_organizers.remove(position);
+
+        msg!("Co-organizer {:?} removed from event {}", co_organizer, event.id);
+        Ok(())
+    }
+
+    /// Cancel an event before it starts
+    /// Called by an event organizer, or by anyone with a passed proposal
+    /// carrying the matching `CancelEvent` action
+    /// Registrations close and members can withdraw without penalty
+    pub fn cancel_event(ctx: Context<ManageEvent>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < event.start_time, ErrorCode::EventAlreadyStarted);
+        require!(event.attended_count == 0, ErrorCode::AttendanceAlreadyRecorded);
+
+        if !event.is_organizer(&ctx.accounts.authority.key()) {
+            let proposal = ctx.accounts.proposal.as_mut()
+                .ok_or(ErrorCode::Unauthorized)?;
+            consume_proposal_action(proposal, ProposalAction::CancelEvent { event_id: event.id })?;
+        }
+
+        event.is_cancelled = true;
+
+        msg!("Event {} cancelled", event.id);
+        Ok(())
+    }
+
+    /// Move an event to a new start time before it starts, keeping its duration
+    /// Called by an event organizer, or by anyone with a passed proposal
+    /// carrying the matching `RescheduleEvent` action
+    /// Registered members can withdraw without penalty during the configured
+    /// grace period
+    pub fn reschedule_event(ctx: Context<ManageEvent>, new_start_time: i64) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < event.start_time, ErrorCode::EventAlreadyStarted);
+        require!(event.attended_count == 0, ErrorCode::AttendanceAlreadyRecorded);
+        require!(new_start_time > current_time, ErrorCode::InvalidEventTime);
+
+        if !event.is_organizer(&ctx.accounts.authority.key()) {
+            let proposal = ctx.accounts.proposal.as_mut()
+                .ok_or(ErrorCode::Unauthorized)?;
+            consume_proposal_action(
+                proposal,
+                ProposalAction::RescheduleEvent { event_id: event.id, new_start_time },
+            )?;
+        }
+
+        event.reschedule(new_start_time, current_time, state.config.reschedule_grace_period)?;
+
+        msg!("Event {} rescheduled to {}, penalty-free withdrawal until {}",
+            event.id, new_start_time, event.penalty_free_until);
+        Ok(())
+    }
+
+    /// Finalize an event (no more attendance can be recorded)
+    pub fn finalize_event(ctx: Context<FinalizeEvent>) -> Result<()> {
+        let state = &ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        require!(!event.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!event.is_cancelled, ErrorCode::EventCancelled);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= event.end_time, ErrorCode::EventNotEnded);
+
+        event.is_finalized = true;
+        event.finalized_at = current_time;
+        msg!("Event {} finalized", event.id);
+        Ok(())
+    }
+
+    /// Finalize an event whose recording window has closed (permissionless crank)
+    pub fn close_out_event(ctx: Context<CloseOutEvent>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &mut ctx.accounts.event;
+        let current_time = Clock::get()?.unix_timestamp;
+        event.check_closable(current_time, state.config.attendance_grace_period)?;
+
+        event.is_finalized = true;
+        event.finalized_at = current_time;
+        msg!("Event {} closed out", event.id);
+        Ok(())
+    }
+
+    /// Settle the deposit of a registration after the event (permissionless crank)
+    /// Refunded for attendance, an excused absence, a seat never obtained
+    /// from the waitlist, or a cancelled event; forfeited to the treasury
+    /// for ghosting once the dispute window has closed and any dispute failed
+    pub fn settle_event_deposit(ctx: Context<SettleEventDeposit>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let event = &ctx.accounts.event;
+        let registration = &mut ctx.accounts.registration;
+        let vault = &mut ctx.accounts.vault;
+        require!(registration.deposit > 0, ErrorCode::NoDepositEscrowed);
+
+        let member_wallet = ctx.accounts.member_wallet.to_account_info();
+        if event.is_cancelled {
+            return refund_deposit(registration, vault, &member_wallet);
+        }
+        require!(event.is_finalized, ErrorCode::EventNotFinalized);
+        if registration.is_waitlisted {
+            return refund_deposit(registration, vault, &member_wallet);
+        }
+
+        match registration.attendance_status {
+            AttendanceStatus::Present | AttendanceStatus::Excused => {
+                refund_deposit(registration, vault, &member_wallet)
+            }
+            AttendanceStatus::Ghosted => {
+                let current_time = Clock::get()?.unix_timestamp;
+                let dispute_closes_at = event.finalized_at.checked_add(state.config.dispute_window)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                require!(current_time > dispute_closes_at, ErrorCode::DisputeWindowOpen);
+                if registration.is_disputed {
+                    require!(
+                        registration.dispute_failed(ctx.accounts.dispute_proposal.as_deref(), current_time),
+                        ErrorCode::DisputePending
+                    );
+                }
+                forfeit_deposit(registration, vault, &mut ctx.accounts.treasury)
+            }
+            _ => err!(ErrorCode::AttendanceNotRecorded),
+        }
+    }
+
+    /// Update competence score for a member (peer review)
+    pub fn update_competence(
+        ctx: Context<UpdateCompetence>,
+        score_delta: i64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let member = &mut ctx.accounts.target_member;
+
+        let scaled_delta = score_delta.checked_mul(SCALING_FACTOR as i64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let applied = adjust_competence(member, &mut state.total_competence, scaled_delta)?;
+        ctx.accounts.ledger.record(
+            Clock::get()?.unix_timestamp, None, ReputationReason::PeerReview, ScoreKind::Competence, applied,
+        );
+
+        msg!("Competence updated for {:?}: delta={}", member.authority, score_delta);
+        Ok(())
+    }
+
+    /// Initialize the skill domain registry (DAO authority only)
+    pub fn initialize_skill_domains(
+        ctx: Context<InitializeSkillDomains>,
+        names: Vec<String>,
+    ) -> Result<()> {
+        require!(names.len() <= MAX_SKILL_DOMAINS, ErrorCode::TooManySkillDomains);
+        for name in names.iter() {
+            require!(
+                !name.is_empty() && name.len() <= MAX_DOMAIN_NAME_LEN,
+                ErrorCode::InvalidSkillDomain
+            );
+        }
+
+        let domains = &mut ctx.accounts.skill_domains;
+        domains.names = names;
+        domains.totals = [0; MAX_SKILL_DOMAINS];
+        domains.bump = ctx.bumps.skill_domains;
+
+        msg!("Skill domains initialized: {}", domains.names.len());
+        Ok(())
+    }
+
+    /// Add a new skill domain to the registry (DAO authority only)
+    pub fn add_skill_domain(ctx: Context<AddSkillDomain>, name: String) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_DOMAIN_NAME_LEN,
+            ErrorCode::InvalidSkillDomain
+        );
+
+        let domains = &mut ctx.accounts.skill_domains;
+        require!(domains.names.len() < MAX_SKILL_DOMAINS, ErrorCode::TooManySkillDomains);
+
+        domains.names.push(name);
+        msg!("Skill domain {} added: {}", domains.names.len() - 1, domains.names[domains.names.len() - 1]);
+        Ok(())
+    }
+
+    /// Create a member's per-domain competence profile
+    /// Every configured domain starts at the member's aggregate competence score
+    pub fn create_competence_profile(ctx: Context<CreateCompetenceProfile>) -> Result<()> {
+        let member = &ctx.accounts.member;
+        require!(member.is_active, ErrorCode::MemberNotActive);
+
+        let domains = &mut ctx.accounts.skill_domains;
+        let profile = &mut ctx.accounts.competence_profile;
+        profile.member = member.authority;
+        profile.scores = [0; MAX_SKILL_DOMAINS];
+        profile.domain_count = 0;
+        profile.bump = ctx.bumps.competence_profile;
+
+        sync_profile_domains(profile, domains, member.competence_score)?;
+
+        msg!("Competence profile created for {:?}", member.authority);
+        Ok(())
+    }
+
+    /// Seed domains added to the registry after the profile was created
+    pub fn sync_competence_profile(ctx: Context<SyncCompetenceProfile>) -> Result<()> {
+        let member = &ctx.accounts.member;
+        require!(member.is_active, ErrorCode::MemberNotActive);
+
+        sync_profile_domains(
+            &mut ctx.accounts.competence_profile,
+            &mut ctx.accounts.skill_domains,
+            member.competence_score,
+        )?;
+
+        msg!("Competence profile synced for {:?}", member.authority);
+        Ok(())
+    }
+
+    /// Update a member's competence in a single skill domain (peer review)
+    pub fn update_domain_competence(
+        ctx: Context<UpdateDomainCompetence>,
+        domain: u8,
+        score_delta: i64,
+    ) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        require!(ctx.accounts.target_member.is_active, ErrorCode::MemberNotActive);
+
+        let scaled_delta = score_delta.checked_mul(SCALING_FACTOR as i64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let applied = adjust_domain_competence(
+            &mut ctx.accounts.competence_profile,
+            &mut ctx.accounts.skill_domains,
+            domain,
+            scaled_delta,
+        )?;
+        ctx.accounts.ledger.record(
+            Clock::get()?.unix_timestamp, None, ReputationReason::PeerReview, ScoreKind::DomainCompetence(domain), applied,
+        );
+
+        msg!(
+            "Domain {} competence updated for {:?}: delta={}",
+            domain,
+            ctx.accounts.target_member.authority,
+            score_delta
+        );
+        Ok(())
+    }
+
+    /// Open a multi-reviewer competence round for a target member
+    pub fn open_competence_round(
+        ctx: Context<OpenCompetenceRound>,
+        window: i64,
+        aggregation: AggregationMethod,
+        slash_outliers: bool,
+        domain: Option<u8>,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        require!(
+            window > 0 && window <= MAX_ROUND_WINDOW,
+            ErrorCode::InvalidRoundWindow
+        );
+        require!(ctx.accounts.target_member.is_active, ErrorCode::MemberNotActive);
+
+        if let Some(domain) = domain {
+            let domains = ctx.accounts.skill_domains.as_ref()
+                .ok_or(ErrorCode::InvalidSkillDomain)?;
+            require!((domain as usize) < domains.names.len(), ErrorCode::InvalidSkillDomain);
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let round = &mut ctx.accounts.round;
+        round.id = state.round_counter;
+        round.target = ctx.accounts.target_authority.key();
+        round.opened_by = ctx.accounts.opener.key();
+        round.opened_at = current_time;
+        round.closes_at = current_time.checked_add(window)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        round.aggregation = aggregation;
+        round.slash_outliers = slash_outliers;
+        round.domain = domain;
+        round.reviews = Vec::new();
+        round.is_closed = false;
+        round.applied_delta = 0;
+        round.bump = ctx.bumps.round;
+
+        state.round_counter = state.round_counter.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Competence round {} opened for {:?}, closes at: {}", round.id, round.target, round.closes_at);
+        Ok(())
+    }
+
+    /// Submit a reviewer score to an open competence round
+    pub fn submit_competence_review(
+        ctx: Context<SubmitCompetenceReview>,
+        score_delta: i64,
+    ) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        require!(
+            (-MAX_REVIEW_SCORE..=MAX_REVIEW_SCORE).contains(&score_delta),
+            ErrorCode::InvalidReviewScore
+        );
+
+        let round = &mut ctx.accounts.round;
+        let reviewer = &ctx.accounts.reviewer_member;
+        require!(!round.is_closed, ErrorCode::RoundClosed);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < round.closes_at, ErrorCode::RoundClosed);
+
+        require!(reviewer.authority != round.target, ErrorCode::CannotReviewSelf);
+        require!(
+            !round.reviews.iter().any(|r| r.reviewer == reviewer.authority),
+            ErrorCode::AlreadyReviewed
+        );
+        require!(round.reviews.len() < MAX_ROUND_REVIEWERS, ErrorCode::RoundFull);
+
+        // Domain rounds are weighted by the reviewer's score in that domain
+        let weight = match round.domain {
+            Some(domain) => {
+                let profile = ctx.accounts.reviewer_profile.as_ref()
+                    .ok_or(ErrorCode::CompetenceProfileRequired)?;
+                profile_domain_score(profile, domain)?
+            }
+            None => reviewer.competence_score,
+        };
+        require!(weight > 0, ErrorCode::InsufficientReputation);
+
+        round.reviews.push(ReviewerScore {
+            reviewer: reviewer.authority,
+            score_delta,
+            weight,
+            is_outlier: false,
+        });
+
+        msg!("Review submitted to round {} by {:?}: delta={}", round.id, reviewer.authority, score_delta);
+        Ok(())
+    }
+
+    /// Close a competence round and apply the weighted consensus delta
+    /// When the round slashes outliers, each outlier reviewer's Member account
+    /// (or CompetenceProfile account for domain rounds) and ReputationLedger
+    /// are passed as pairs in `remaining_accounts`, in review order
+    pub fn close_competence_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseCompetenceRound<'info>>,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let round = &mut ctx.accounts.round;
+        require!(!round.is_closed, ErrorCode::RoundClosed);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time >= round.closes_at, ErrorCode::RoundStillOpen);
+
+        round.is_closed = true;
+
+        if round.reviews.len() < MIN_ROUND_REVIEWERS {
+            msg!("Round {} closed without quorum ({} reviews)", round.id, round.reviews.len());
+            return Ok(());
+        }
+
+        let consensus = match round.aggregation {
+            AggregationMethod::WeightedMedian => weighted_median(&round.reviews),
+            AggregationMethod::TrimmedMean => weighted_trimmed_mean(&round.reviews),
+        };
+
+        let member = &mut ctx.accounts.target_member;
+        let score_kind = match round.domain {
+            Some(domain) => ScoreKind::DomainCompetence(domain),
+            None => ScoreKind::Competence,
+        };
+        if member.is_active {
+            round.applied_delta = match round.domain {
+                Some(domain) => adjust_domain_competence(
+                    ctx.accounts.target_profile.as_mut().ok_or(ErrorCode::CompetenceProfileRequired)?,
+                    ctx.accounts.skill_domains.as_mut().ok_or(ErrorCode::InvalidSkillDomain)?,
+                    domain,
+                    consensus,
+                )?,
+                None => adjust_competence(member, &mut state.total_competence, consensus)?,
+            };
+            ctx.accounts.target_ledger.record(
+                current_time, Some(round.id), ReputationReason::PeerReview, score_kind, round.applied_delta,
+            );
+        }
+
+        if round.slash_outliers {
+            let round_id = round.id;
+            let round_domain = round.domain;
+            let mut outlier_accounts = ctx.remaining_accounts.iter();
+
+            for review in round.reviews.iter_mut() {
+                if !is_outlier(review.score_delta, consensus) {
+                    continue;
+                }
+                review.is_outlier = true;
+
+                let account_info = outlier_accounts.next()
+                    .ok_or(ErrorCode::InvalidRemainingAccounts)?;
+                let ledger_info = outlier_accounts.next()
+                    .ok_or(ErrorCode::InvalidRemainingAccounts)?;
+                let mut reviewer_ledger = load_ledger(ledger_info, &review.reviewer)?;
+                let applied = match round_domain {
+                    Some(domain) => {
+                        let mut reviewer_profile = load_competence_profile(account_info, &review.reviewer)?;
+                        let applied = adjust_domain_competence(
+                            &mut reviewer_profile,
+                            ctx.accounts.skill_domains.as_mut().ok_or(ErrorCode::InvalidSkillDomain)?,
+                            domain,
+                            -(OUTLIER_PENALTY as i64),
+                        )?;
+                        reviewer_profile.exit(&crate::ID)?;
+                        applied
+                    }
+                    None => {
+                        let mut reviewer_member = load_member(account_info, &review.reviewer)?;
+                        if !reviewer_member.is_active {
+                            continue;
+                        }
+                        let applied = adjust_competence(&mut reviewer_member, &mut state.total_competence, -(OUTLIER_PENALTY as i64))?;
+                        reviewer_member.exit(&crate::ID)?;
+                        applied
+                    }
+                };
+                reviewer_ledger.record(current_time, Some(round_id), ReputationReason::PeerReview, score_kind, applied);
+                reviewer_ledger.exit(&crate::ID)?;
+                msg!("Outlier reviewer penalized: {:?}", review.reviewer);
+            }
+        }
+
+        msg!("Round {} closed: consensus={}, applied={}", round.id, consensus, round.applied_delta);
+        Ok(())
+    }
+
+    /// Create a new proposal
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        title: String,
+        description: String,
+        proposal_type: ProposalType,
+        voting_period: i64,
+        domain: Option<u8>,
+        action: Option<ProposalAction>,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let member = &ctx.accounts.member;
+        require!(member.is_active, ErrorCode::MemberNotActive);
+
+        // Large treasury spends need an absolute majority
+        let requires_critical = match action {
+            Some(
+                ProposalAction::TreasuryWithdrawal { amount, .. }
+                | ProposalAction::FundDistribution { amount, .. }
+            ) => state.config.requires_critical(amount),
+            Some(ProposalAction::TokenTransfer { mint, amount, .. }) => {
+                state.config.requires_critical_token_transfer(&mint, amount)
+            }
+            _ => false,
+        };
+        require!(
+            proposal_type == ProposalType::Critical || !requires_critical,
+            ErrorCode::CriticalProposalRequired
+        );
+
+        // Calculate total voting power snapshot at proposal creation
+        let total_power_snapshot = match domain {
+            Some(domain) => {
+                let domains = ctx.accounts.skill_domains.as_ref()
+                    .ok_or(ErrorCode::InvalidSkillDomain)?;
+                require!((domain as usize) < domains.names.len(), ErrorCode::InvalidSkillDomain);
+                calculate_domain_voting_power(state, domains, domain)
+            }
+            None => calculate_total_voting_power(state),
+        };
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        proposal.id = state.proposal_counter;
+        proposal.proposer = member.authority;
+        proposal.title = title;
+        proposal.description = description;
+        proposal.proposal_type = proposal_type;
+        proposal.domain = domain;
+        proposal.action = action;
+        proposal.executed = false;
+        proposal.votes_for = 0;
+        proposal.votes_against = 0;
+        proposal.total_power_snapshot = total_power_snapshot;
+        proposal.created_at = current_time;
+        proposal.voting_ends_at = current_time.checked_add(voting_period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        proposal.status = ProposalStatus::Active;
+        proposal.bump = ctx.bumps.proposal;
+
+        state.proposal_counter = state.proposal_counter.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Proposal {} created: {}", proposal.id, proposal.title);
+        Ok(())
+    }
+
+    /// Vote on a proposal
+    pub fn vote(ctx: Context<Vote>, support: bool) -> Result<()> {
+        let state = &ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let member = &ctx.accounts.member;
+        require!(member.is_active, ErrorCode::MemberNotActive);
+
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < proposal.voting_ends_at,
+            ErrorCode::VotingPeriodEnded
+        );
+
+        let vote_record = &mut ctx.accounts.vote_record;
+        require!(!vote_record.has_voted, ErrorCode::AlreadyVoted);
+
+        // Domain-tagged proposals use the member's score in that domain
+        let domain_competence = match proposal.domain {
+            Some(domain) => {
+                let profile = ctx.accounts.competence_profile.as_ref()
+                    .ok_or(ErrorCode::CompetenceProfileRequired)?;
+                let domains = ctx.accounts.skill_domains.as_ref()
+                    .ok_or(ErrorCode::InvalidSkillDomain)?;
+                Some((profile_domain_score(profile, domain)?, domains.totals[domain as usize]))
+            }
+            None => None,
+        };
+
+        // Calculate voting weight
+        let voting_weight = calculate_voting_weight(member, state, domain_competence);
+        require!(voting_weight > 0, ErrorCode::InsufficientReputation);
+
+        if support {
+            proposal.votes_for = proposal.votes_for.checked_add(voting_weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        } else {
+            proposal.votes_against = proposal.votes_against.checked_add(voting_weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        vote_record.voter = member.authority;
+        vote_record.proposal_id = proposal.id;
+        vote_record.support = support;
+        vote_record.weight = voting_weight;
+        vote_record.has_voted = true;
+        vote_record.bump = ctx.bumps.vote_record;
+
+        msg!(
+            "Vote recorded: {:?} voted {} with weight {}",
+            member.authority,
+            if support { "FOR" } else { "AGAINST" },
+            voting_weight
+        );
+        Ok(())
+    }
+
+    /// Execute/finalize a proposal after voting period
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let state = &ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= proposal.voting_ends_at,
+            ErrorCode::VotingPeriodNotEnded
+        );
+
+        // Determine if proposal passed based on type
+        let passed = proposal.passes();
+
+        proposal.status = if passed {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        };
+
+        msg!(
+            "Proposal {} {}: for={}, against={}, threshold={}",
+            proposal.id,
+            if passed { "PASSED" } else { "REJECTED" },
+            proposal.votes_for,
+            proposal.votes_against,
+            proposal.total_power_snapshot / 2
+        );
+        Ok(())
+    }
+
+    /// Ban a member (requires passed Critical proposal)
+    pub fn ban_member(ctx: Context<BanMember>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let member = &mut ctx.accounts.target_member;
+        require!(member.is_active, ErrorCode::MemberNotActive);
+
+        let ban_time = Clock::get()?.unix_timestamp;
+        let ledger = &mut ctx.accounts.ledger;
+        ledger.record(ban_time, None, ReputationReason::Ban, ScoreKind::Presence, -(member.presence_score as i64));
+        ledger.record(ban_time, None, ReputationReason::Ban, ScoreKind::Competence, -(member.competence_score as i64));
+
+        // Remove member's scores from totals
+        state.total_presence = state.total_presence.checked_sub(member.presence_score)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        state.total_competence = state.total_competence.checked_sub(member.competence_score)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        state.active_members = state.active_members.checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        member.is_active = false;
+        member.presence_score = 0;
+        member.competence_score = 0;
+
+        // Remove the member's domain scores from the domain totals
+        if let Some(profile) = ctx.accounts.competence_profile.as_mut() {
+            let domains = ctx.accounts.skill_domains.as_mut()
+                .ok_or(ErrorCode::InvalidSkillDomain)?;
+            for domain in 0..profile.domain_count as usize {
+                domains.totals[domain] = domains.totals[domain].checked_sub(profile.scores[domain])
+                    .ok_or(ErrorCode::SlashingOverflow)?;
+            }
+            profile.scores = [0; MAX_SKILL_DOMAINS];
+        }
+
+        msg!("Member {:?} has been banned", member.authority);
+        
+        // Check kill switch after banning
+        if state.active_members < MIN_QUORUM {
+            msg!("WARNING: DAO is now frozen (< {} active members)", MIN_QUORUM);
+        }
+        
+        Ok(())
+    }
+
+    /// Coopt a new member (requires passed Critical proposal)
+    pub fn coopt_member(ctx: Context<CooptMember>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let member = &mut ctx.accounts.new_member;
+        
+        // New coopted members start with minimal scores
+        let initial_presence = SCALING_FACTOR; // 1 * SCALING_FACTOR
+        let initial_competence = SCALING_FACTOR; // 1 * SCALING_FACTOR
+
+        member.authority = ctx.accounts.new_member_authority.key();
+        member.presence_score = initial_presence;
+        member.competence_score = initial_competence;
+        member.is_active = true;
+        member.is_genesis = false;
+        member.joined_at = Clock::get()?.unix_timestamp;
+        member.excused_absences = [0; MAX_EXCUSED_ABSENCES];
+        member.current_streak = 0;
+        member.longest_streak = 0;
+        member.bump = ctx.bumps.new_member;
+
+        let ledger = &mut ctx.accounts.ledger;
+        ledger.member = member.authority;
+        ledger.bump = ctx.bumps.ledger;
+        ledger.record(member.joined_at, None, ReputationReason::Coopt, ScoreKind::Presence, initial_presence as i64);
+        ledger.record(member.joined_at, None, ReputationReason::Coopt, ScoreKind::Competence, initial_competence as i64);
+
+        // Update global state
+        state.total_presence = state.total_presence.checked_add(initial_presence)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        state.total_competence = state.total_competence.checked_add(initial_competence)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        state.active_members = state.active_members.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("New member coopted: {:?}", member.authority);
+        Ok(())
+    }
+
+    /// Create the DAO treasury vault (DAO authority only)
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_deposited = 0;
+        treasury.total_withdrawn = 0;
+        treasury.transfer_count = 0;
+        treasury.bump = ctx.bumps.treasury;
+
+        msg!("Treasury initialized");
+        Ok(())
+    }
+
+    /// Deposit SOL into the treasury (anyone)
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_deposited = treasury.total_deposited.checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Deposit of {} lamports from {:?}", amount, ctx.accounts.depositor.key());
+        Ok(())
+    }
+
+    /// Send SOL from the treasury as the action of a passed proposal
+    /// Amounts above the configured threshold need a Critical proposal
+    /// Every outflow is recorded as a TreasuryTransfer
+    pub fn withdraw_from_treasury(ctx: Context<WithdrawFromTreasury>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let proposal = &mut ctx.accounts.proposal;
+        let (recipient, amount) = match proposal.action {
+            Some(ProposalAction::TreasuryWithdrawal { recipient, amount }) => (recipient, amount),
+            _ => return err!(ErrorCode::ProposalActionMismatch),
+        };
+        require_keys_eq!(ctx.accounts.recipient.key(), recipient, ErrorCode::ProposalActionMismatch);
+        require!(
+            proposal.proposal_type == ProposalType::Critical || !state.config.requires_critical(amount),
+            ErrorCode::CriticalProposalRequired
+        );
+        consume_proposal_action(proposal, ProposalAction::TreasuryWithdrawal { recipient, amount })?;
+
+        let treasury = &mut ctx.accounts.treasury;
+        pay_from_treasury(treasury, &ctx.accounts.recipient.to_account_info(), amount)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let transfer = &mut ctx.accounts.transfer_record;
+        transfer.id = treasury.transfer_count;
+        transfer.proposal_id = Some(proposal.id);
+        transfer.recipient = recipient;
+        transfer.mint = None;
+        transfer.amount = amount;
+        transfer.executed_at = current_time;
+        transfer.bump = ctx.bumps.transfer_record;
+
+        treasury.transfer_count = treasury.transfer_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Treasury transfer {}: {} lamports to {:?} (proposal {})",
+            transfer.id, amount, recipient, proposal.id);
+        Ok(())
+    }
+
+    /// Create the treasury's associated token account for a mint (anyone)
+    /// Works for both SPL Token and Token-2022 mints; sponsors then
+    /// transfer tokens to this account directly
+    pub fn create_treasury_token_account(ctx: Context<CreateTreasuryTokenAccount>) -> Result<()> {
+        msg!("Treasury token account {:?} created for mint {:?}",
+            ctx.accounts.treasury_token_account.key(), ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    /// Send SPL tokens from the treasury as the action of a passed proposal
+    /// The treasury PDA signs the transfer out of its associated token account
+    /// Transfers above the mint's threshold (any, without one) need a Critical proposal
+    pub fn withdraw_treasury_tokens(ctx: Context<WithdrawTreasuryTokens>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let proposal = &mut ctx.accounts.proposal;
+        let (mint, destination, amount) = match proposal.action {
+            Some(ProposalAction::TokenTransfer { mint, destination, amount }) => (mint, destination, amount),
+            _ => return err!(ErrorCode::ProposalActionMismatch),
+        };
+        require_keys_eq!(ctx.accounts.mint.key(), mint, ErrorCode::ProposalActionMismatch);
+        require_keys_eq!(ctx.accounts.destination.key(), destination, ErrorCode::ProposalActionMismatch);
+        require!(
+            proposal.proposal_type == ProposalType::Critical
+                || !state.config.requires_critical_token_transfer(&mint, amount),
+            ErrorCode::CriticalProposalRequired
+        );
+        consume_proposal_action(proposal, ProposalAction::TokenTransfer { mint, destination, amount })?;
+
+        require!(
+            amount <= ctx.accounts.treasury_token_account.amount,
+            ErrorCode::InsufficientTreasuryFunds
+        );
+
+        let bump = ctx.accounts.treasury.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &[bump]]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.treasury_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.treasury.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let treasury = &mut ctx.accounts.treasury;
+        let transfer = &mut ctx.accounts.transfer_record;
+        transfer.id = treasury.transfer_count;
+        transfer.proposal_id = Some(proposal.id);
+        transfer.recipient = destination;
+        transfer.mint = Some(mint);
+        transfer.amount = amount;
+        transfer.executed_at = current_time;
+        transfer.bump = ctx.bumps.transfer_record;
+
+        treasury.transfer_count = treasury.transfer_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Treasury transfer {}: {} of mint {:?} to {:?} (proposal {})",
+            transfer.id, amount, mint, destination, proposal.id);
+        Ok(())
+    }
+
+    /// Post a bounty (DAO authority only)
+    /// `reviewer` can approve the deliverable; without one, or for rewards
+    /// above the critical spend threshold, approval goes through governance
+    pub fn create_bounty(
+        ctx: Context<CreateBounty>,
+        description: String,
+        reward: u64,
+        competence_reward: u64,
+        reviewer: Option<Pubkey>,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        require!(reward > 0 || competence_reward > 0, ErrorCode::InvalidAmount);
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.id = state.bounty_counter;
+        bounty.description = description;
+        bounty.reward = reward;
+        bounty.competence_reward = competence_reward;
+        bounty.reviewer = reviewer;
+        bounty.status = BountyStatus::Open;
+        bounty.claimant = None;
+        bounty.deliverable_uri = String::new();
+        bounty.deliverable_hash = [0; 32];
+        bounty.created_at = Clock::get()?.unix_timestamp;
+        bounty.completed_at = 0;
+        bounty.bump = ctx.bumps.bounty;
+
+        state.bounty_counter = state.bounty_counter.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Bounty {} posted: {} lamports, {} competence", bounty.id, reward, competence_reward);
+        Ok(())
+    }
+
+    /// Claim an open bounty (active members other than its reviewer)
+    pub fn claim_bounty(ctx: Context<ClaimBounty>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.check_claimable(&ctx.accounts.claimant.key())?;
+
+        bounty.status = BountyStatus::Claimed;
+        bounty.claimant = Some(ctx.accounts.claimant.key());
+
+        msg!("Bounty {} claimed by {:?}", bounty.id, ctx.accounts.claimant.key());
+        Ok(())
+    }
+
+    /// Submit the deliverable of a claimed bounty (claimant only)
+    /// Resubmitting replaces the previous deliverable until it is approved
+    pub fn submit_bounty(
+        ctx: Context<SubmitBounty>,
+        deliverable_uri: String,
+        deliverable_hash: [u8; 32],
+    ) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let bounty = &mut ctx.accounts.bounty;
+        require!(
+            bounty.status == BountyStatus::Claimed || bounty.status == BountyStatus::Submitted,
+            ErrorCode::BountyNotClaimed
+        );
+
+        bounty.status = BountyStatus::Submitted;
+        bounty.deliverable_uri = deliverable_uri;
+        bounty.deliverable_hash = deliverable_hash;
+
+        msg!("Deliverable submitted for bounty {}", bounty.id);
+        Ok(())
+    }
+
+    /// Reopen a claimed bounty (claimant, reviewer or DAO authority)
+    pub fn release_bounty(ctx: Context<ReleaseBounty>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let bounty = &mut ctx.accounts.bounty;
+        require!(
+            bounty.status == BountyStatus::Claimed || bounty.status == BountyStatus::Submitted,
+            ErrorCode::BountyNotClaimed
+        );
+
+        let authority = ctx.accounts.authority.key();
+        require!(
+            bounty.claimant == Some(authority)
+                || bounty.reviewer == Some(authority)
+                || state.authority == authority,
+            ErrorCode::Unauthorized
+        );
+
+        bounty.status = BountyStatus::Open;
+        bounty.claimant = None;
+        bounty.deliverable_uri = String::new();
+        bounty.deliverable_hash = [0; 32];
+
+        msg!("Bounty {} reopened", bounty.id);
+        Ok(())
+    }
+
+    /// Approve a submitted bounty: pay the SOL reward from the treasury and
+    /// apply the competence reward, recorded in the claimant's ledger
+    /// Signed by the reviewer, or backed by a passed ApproveBounty proposal
+    /// naming the claimant and deliverable (Critical when the reward is above
+    /// the critical spend threshold); the payout is recorded as a TreasuryTransfer
+    pub fn approve_bounty(ctx: Context<ApproveBounty>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let bounty = &mut ctx.accounts.bounty;
+        require!(bounty.status == BountyStatus::Submitted, ErrorCode::BountyNotSubmitted);
+
+        let requires_critical = state.config.requires_critical(bounty.reward);
+        let mut proposal_id = None;
+        if bounty.requires_proposal(&ctx.accounts.authority.key(), &state.config) {
+            let proposal = ctx.accounts.proposal.as_mut()
+                .ok_or(ErrorCode::Unauthorized)?;
+            require!(
+                proposal.proposal_type == ProposalType::Critical || !requires_critical,
+                ErrorCode::CriticalProposalRequired
+            );
+            consume_proposal_action(proposal, bounty.approval_action()?)?;
+            proposal_id = Some(proposal.id);
+        }
+
+        let current_time = Clock::get()?.unix_timestamp;
+        if bounty.reward > 0 {
+            let treasury = &mut ctx.accounts.treasury;
+            let claimant = ctx.accounts.claimant.key();
+            pay_from_treasury(treasury, &ctx.accounts.claimant.to_account_info(), bounty.reward)?;
+
+            let transfer = ctx.accounts.transfer_record.as_mut()
+                .ok_or(ErrorCode::TransferRecordRequired)?;
+            transfer.id = treasury.transfer_count;
+            transfer.proposal_id = proposal_id;
+            transfer.recipient = claimant;
+            transfer.mint = None;
+            transfer.amount = bounty.reward;
+            transfer.executed_at = current_time;
+            transfer.bump = ctx.bumps.transfer_record.ok_or(ErrorCode::TransferRecordRequired)?;
+
+            treasury.transfer_count = treasury.transfer_count.checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        let member = &mut ctx.accounts.member;
+        let applied = bounty.complete(member, &mut state.total_competence, &mut ctx.accounts.ledger, current_time)?;
+
+        msg!("Bounty {} completed by {:?}: {} lamports, {} competence",
+            bounty.id, member.authority, bounty.reward, applied);
+        Ok(())
+    }
+
+    /// Open a reward distribution epoch as the action of a passed proposal
+    /// Moves the amount from the treasury into the distribution account,
+    /// recorded as a TreasuryTransfer; members' shares, computed off-chain
+    /// with the proposal's formula, are committed by the Merkle root, whose
+    /// leaves use the distribution ID named in the proposal
+    pub fn create_distribution(ctx: Context<CreateDistribution>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let proposal = &mut ctx.accounts.proposal;
+        let action = proposal.action.ok_or(ErrorCode::ProposalActionMismatch)?;
+        let ProposalAction::FundDistribution { distribution_id, merkle_root, amount, claim_deadline, formula } = action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        require!(distribution_id == state.distribution_counter, ErrorCode::DistributionIdMismatch);
+        require!(
+            proposal.proposal_type == ProposalType::Critical || !state.config.requires_critical(amount),
+            ErrorCode::CriticalProposalRequired
+        );
+        consume_proposal_action(proposal, action)?;
+
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(claim_deadline > current_time, ErrorCode::InvalidClaimDeadline);
+
+        let treasury = &mut ctx.accounts.treasury;
+        pay_from_treasury(treasury, &ctx.accounts.distribution.to_account_info(), amount)?;
+
+        let transfer = &mut ctx.accounts.transfer_record;
+        transfer.id = treasury.transfer_count;
+        transfer.proposal_id = Some(proposal.id);
+        transfer.recipient = ctx.accounts.distribution.key();
+        transfer.mint = None;
+        transfer.amount = amount;
+        transfer.executed_at = current_time;
+        transfer.bump = ctx.bumps.transfer_record;
+
+        treasury.transfer_count = treasury.transfer_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let distribution = &mut ctx.accounts.distribution;
+        distribution.id = distribution_id;
+        distribution.proposal_id = proposal.id;
+        distribution.merkle_root = merkle_root;
+        distribution.formula = formula;
+        distribution.total_amount = amount;
+        distribution.claimed_amount = 0;
+        distribution.claim_count = 0;
+        distribution.claim_deadline = claim_deadline;
+        distribution.created_at = current_time;
+        distribution.is_reclaimed = false;
+        distribution.bump = ctx.bumps.distribution;
+
+        state.distribution_counter = state.distribution_counter.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Distribution {} opened: {} lamports claimable until {}",
+            distribution.id, amount, claim_deadline);
+        Ok(())
+    }
+
+    /// Claim a share of a distribution with a Merkle proof of
+    /// (distribution ID, claimant, amount)
+    /// The claim receipt prevents claiming twice
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let distribution = &mut ctx.accounts.distribution;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            !distribution.is_reclaimed && current_time <= distribution.claim_deadline,
+            ErrorCode::ClaimDeadlinePassed
+        );
+
+        let claimant = ctx.accounts.claimant.key();
+        let leaf = distribution_leaf(distribution.id, &claimant, amount);
+        require!(
+            verify_merkle_proof(&proof, &distribution.merkle_root, leaf),
+            ErrorCode::InvalidMerkleProof
+        );
+
+        let claimed_amount = distribution.claimed_amount.checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(claimed_amount <= distribution.total_amount, ErrorCode::InsufficientDistributionFunds);
+
+        **distribution.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.claimant.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        distribution.claimed_amount = claimed_amount;
+        distribution.claim_count = distribution.claim_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let receipt = &mut ctx.accounts.receipt;
+        receipt.distribution_id = distribution.id;
+        receipt.claimant = claimant;
+        receipt.amount = amount;
+        receipt.claimed_at = current_time;
+        receipt.bump = ctx.bumps.receipt;
+
+        msg!("{:?} claimed {} lamports from distribution {}", claimant, amount, distribution.id);
+        Ok(())
+    }
+
+    /// Return the unclaimed part of a distribution to the treasury after
+    /// the claim deadline (permissionless crank)
+    pub fn reclaim_distribution(ctx: Context<ReclaimDistribution>) -> Result<()> {
+        let state = &ctx.accounts.state;
+
+        // Kill switch check
+        require!(
+            state.active_members >= MIN_QUORUM,
+            ErrorCode::DaoShutdown
+        );
+
+        let distribution = &mut ctx.accounts.distribution;
+        let treasury = &mut ctx.accounts.treasury;
+        let current_time = Clock::get()?.unix_timestamp;
+        let unclaimed = distribution.reclaim(treasury, current_time)?;
+
+        **distribution.to_account_info().try_borrow_mut_lamports()? -= unclaimed;
+        **treasury.to_account_info().try_borrow_mut_lamports()? += unclaimed;
+
+        msg!("Distribution {}: {} unclaimed lamports returned to the treasury", distribution.id, unclaimed);
+        Ok(())
+    }
+
+    /// Create the reputation ledger of a member who joined before ledgers existed
+    pub fn create_reputation_ledger(ctx: Context<CreateReputationLedger>) -> Result<()> {
+        let ledger = &mut ctx.accounts.ledger;
+        ledger.member = ctx.accounts.member.authority;
+        ledger.bump = ctx.bumps.ledger;
+
+        msg!("Reputation ledger created for {:?}", ledger.member);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// HELPER FUNCTIONS
+// ============================================================================
+
+/// Address of the treasury's associated token account for a mint
+/// `token_program` is the SPL Token or Token-2022 program owning the mint
+pub fn treasury_token_address(mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &crate::ID);
+    get_associated_token_address_with_program_id(&treasury, mint, token_program)
+}
+
+/// Weight of a member in a reward distribution, to be snapshotted off-chain
+/// for every member and normalized into shares of the distributed amount
+pub fn distribution_weight(formula: DistributionFormula, member: &Member, state: &State) -> u128 {
+    match formula {
+        DistributionFormula::VotingWeight => calculate_voting_weight(member, state, None),
+        DistributionFormula::Presence => member.presence_score as u128,
+        DistributionFormula::Competence => member.competence_score as u128,
+    }
+}
+
+/// Merkle leaf committing the amount a claimant can take from a distribution
+pub fn distribution_leaf(distribution_id: u64, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[&[0u8], &distribution_id.to_le_bytes(), claimant.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Parent of two Merkle nodes, hashed in sorted order so that proofs
+/// don't need left/right flags
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[1u8], left, right]).to_bytes()
+}
+
+/// Whether `proof` links `leaf` to `root`
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| merkle_parent(&node, sibling)) == *root
+}
+
+/// Calculate voting weight for a member
+/// Formula: S_member = (Pres_member/Pres_total) × (Comp_member/Comp_total)
+/// `domain_competence` is the (member score, total) pair of a skill domain,
+/// used instead of the aggregate competence for domain-tagged proposals
+/// Returns scaled u128 to prevent overflow
+pub fn calculate_voting_weight(
+    member: &Member,
+    state: &State,
+    domain_competence: Option<(u64, u64)>,
+) -> u128 {
+    let (competence, total_competence) = domain_competence
+        .unwrap_or((member.competence_score, state.total_competence));
+
+    if state.total_presence == 0 || total_competence == 0 {
+        return 0;
+    }
+    
+    let p_m = member.presence_score as u128;
+    let c_m = competence as u128;
+    let p_tot = state.total_presence as u128;
+    let c_tot = total_competence as u128;
+    let scale = SCALING_FACTOR as u128;
+
+    // (p_m * c_m * SCALING_FACTOR) / (p_tot * c_tot)
+    let numerator = p_m.saturating_mul(c_m).saturating_mul(scale);
+    let denominator = p_tot.saturating_mul(c_tot);
+    
+    if denominator == 0 {
+        0
+    } else {
+        numerator / denominator
+    }
+}
+
+/// Calculate total voting power (sum of all members' weights = SCALING_FACTOR)
+fn calculate_total_voting_power(state: &State) -> u128 {
+    // By the formula, sum of all weights equals SCALING_FACTOR
+    // when everyone has non-zero scores
+    if state.total_presence == 0 || state.total_competence == 0 {
+        0
+    } else {
+        SCALING_FACTOR as u128
+    }
+}
+
+/// Calculate total voting power for a domain-tagged proposal
+fn calculate_domain_voting_power(state: &State, domains: &SkillDomains, domain: u8) -> u128 {
+    if state.total_presence == 0 || domains.totals[domain as usize] == 0 {
+        0
+    } else {
+        SCALING_FACTOR as u128
+    }
+}
+
+/// Member's score in a skill domain, failing if the profile predates the domain
+fn profile_domain_score(profile: &CompetenceProfile, domain: u8) -> Result<u64> {
+    require!(domain < profile.domain_count, ErrorCode::CompetenceProfileOutOfSync);
+    Ok(profile.scores[domain as usize])
+}
+
+/// Seed every registry domain the profile does not cover yet with `baseline`
+fn sync_profile_domains(
+    profile: &mut CompetenceProfile,
+    domains: &mut SkillDomains,
+    baseline: u64,
+) -> Result<()> {
+    for domain in profile.domain_count as usize..domains.names.len() {
+        profile.scores[domain] = baseline;
+        domains.totals[domain] = domains.totals[domain].checked_add(baseline)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+    profile.domain_count = domains.names.len() as u8;
+    Ok(())
+}
+
+/// Apply a signed, already-scaled competence delta to one skill domain
+/// Negative deltas are capped at the member's current domain score
+/// Returns the delta actually applied
+pub fn adjust_domain_competence(
+    profile: &mut CompetenceProfile,
+    domains: &mut SkillDomains,
+    domain: u8,
+    delta: i64,
+) -> Result<i64> {
+    let score = profile_domain_score(profile, domain)?;
+    let index = domain as usize;
+
+    if delta >= 0 {
+        let delta_abs = delta as u64;
+        profile.scores[index] = score.checked_add(delta_abs)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        domains.totals[index] = domains.totals[index].checked_add(delta_abs)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(delta)
+    } else {
+        let actual_delta = delta.unsigned_abs().min(score);
+        profile.scores[index] = score.checked_sub(actual_delta)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        domains.totals[index] = domains.totals[index].checked_sub(actual_delta)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        Ok(-(actual_delta as i64))
+    }
+}
+
+/// Charge LATE_PENALTY, scaled by the event's category and the configured
+/// penalty curve, when registering or withdrawing within the category's
+/// late window before the event starts
+fn charge_late_penalty(
+    total_presence: &mut u64,
+    config: &DaoConfig,
+    event: &TrackSession,
+    member: &mut Member,
+    ledger: &mut ReputationLedger,
+    reason: ReputationReason,
+    current_time: i64,
+) -> Result<()> {
+    let time_until_event = event.start_time.checked_sub(current_time)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let penalty = late_penalty_amount(config, &event.schedule, time_until_event)?;
+    if penalty > 0 {
+        let applied = adjust_presence(member, total_presence, -(penalty as i64))?;
+        ledger.record(current_time, Some(event.id), reason, ScoreKind::Presence, applied);
+        msg!("Late penalty applied: {}", applied);
+    }
+    Ok(())
+}
+
+/// Escrow the event deposit (if the event requires one) from the member
+/// into the event vault
+fn escrow_deposit<'info>(
+    deposit_amount: u64,
+    registration: &mut EventRegistration,
+    vault: Option<&mut Account<'info, EventVault>>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if deposit_amount == 0 {
+        return Ok(());
+    }
+    let vault = vault.ok_or(ErrorCode::EventVaultRequired)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: payer.to_account_info(),
+                to: vault.to_account_info(),
+            },
+        ),
+        deposit_amount,
+    )?;
+
+    vault.escrowed = vault.escrowed.checked_add(deposit_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    registration.deposit = deposit_amount;
+    msg!("Deposit of {} lamports escrowed", deposit_amount);
+    Ok(())
+}
+
+/// Move a registration's escrowed deposit out of the event vault
+fn take_deposit(
+    registration: &mut EventRegistration,
+    vault: &mut Account<EventVault>,
+    to: &AccountInfo,
+) -> Result<u64> {
+    let amount = registration.deposit;
+    vault.escrowed = vault.escrowed.checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+
+    registration.deposit = 0;
+    Ok(amount)
+}
+
+/// Return a registration's deposit to the member
+fn refund_deposit(
+    registration: &mut EventRegistration,
+    vault: &mut Account<EventVault>,
+    member_wallet: &AccountInfo,
+) -> Result<()> {
+    let amount = take_deposit(registration, vault, member_wallet)?;
+    vault.total_refunded = vault.total_refunded.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!("Deposit of {} lamports refunded to {:?}", amount, registration.member);
+    Ok(())
+}
+
+/// Forfeit a registration's deposit to the treasury
+fn forfeit_deposit(
+    registration: &mut EventRegistration,
+    vault: &mut Account<EventVault>,
+    treasury: &mut Account<Treasury>,
+) -> Result<()> {
+    let amount = take_deposit(registration, vault, &treasury.to_account_info())?;
+    vault.total_forfeited = vault.total_forfeited.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    treasury.total_deposited = treasury.total_deposited.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!("Deposit of {} lamports from {:?} forfeited to the treasury", amount, registration.member);
+    Ok(())
+}
+
+/// Send SOL out of the treasury, keeping the vault rent-exempt
+fn pay_from_treasury(
+    treasury: &mut Account<Treasury>,
+    recipient: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    let treasury_info = treasury.to_account_info();
+    let minimum_balance = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(minimum_balance);
+    require!(amount <= available, ErrorCode::InsufficientTreasuryFunds);
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+
+    treasury.total_withdrawn = treasury.total_withdrawn.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Check that a proposal passed with the expected action, and mark the
+/// action as executed so the proposal cannot be reused
+fn consume_proposal_action(proposal: &mut Proposal, expected: ProposalAction) -> Result<()> {
+    require!(proposal.status == ProposalStatus::Passed, ErrorCode::ProposalNotPassed);
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(proposal.action == Some(expected), ErrorCode::ProposalActionMismatch);
+
+    proposal.executed = true;
+    Ok(())
+}
+
+/// Apply the presence reward or penalty for one registration at an event,
+/// scaled by the event's category schedule
+/// Fails if attendance was already recorded for this registration
+pub fn apply_attendance(
+    total_presence: &mut u64,
+    event: &mut TrackSession,
+    registration: &mut EventRegistration,
+    member: &mut Member,
+    ledger: &mut ReputationLedger,
+    was_present: bool,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        registration.attendance_status == AttendanceStatus::Unrecorded,
+        ErrorCode::AttendanceAlreadyRecorded
+    );
+
+    let schedule = event.schedule;
+    match (registration.is_registered, was_present) {
+        // Registered and present: reward, with a bonus for long attendance streaks
+        (true, true) => {
+            member.current_streak = member.current_streak.checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            member.longest_streak = member.longest_streak.max(member.current_streak);
+            let bonus_bps = event.streak_bonus_bps(member.current_streak);
+            let reward = apply_bps(schedule.scaled_reward(ATTENDANCE_REWARD)?, bonus_bps)?;
+            let applied = adjust_presence(member, total_presence, reward as i64)?;
+            ledger.record(current_time, Some(event.id), ReputationReason::Attendance, ScoreKind::Presence, applied);
+            registration.has_attended = true;
+            registration.attendance_status = AttendanceStatus::Present;
+            registration.attendance_delta = applied;
+            event.attended_count = event.attended_count.checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            msg!("Attendance recorded: +{} presence", applied);
+        }
+        // Registered, absent with an approved excuse: no penalty
+        (true, false) if registration.excuse_status == ExcuseStatus::Approved => {
+            registration.attendance_status = AttendanceStatus::Excused;
+            registration.attendance_delta = 0;
+            msg!("Excused absence: no penalty");
+        }
+        // Registered but absent (ghosting): heavy penalty
+        (true, false) => {
+            let penalty = schedule.scaled_penalty(GHOSTING_PENALTY)?;
+            let applied = adjust_presence(member, total_presence, -(penalty as i64))?;
+            registration.broken_streak = member.current_streak;
+            member.current_streak = 0;
+            ledger.record(current_time, Some(event.id), ReputationReason::Ghosting, ScoreKind::Presence, applied);
+            registration.attendance_status = AttendanceStatus::Ghosted;
+            registration.attendance_delta = applied;
+            msg!("Ghosting penalty applied: {}", applied);
+        }
+        // Not registered but present (oubli): penalty
+        (false, true) => {
+            let penalty = schedule.scaled_penalty(OUBLI_PENALTY)?;
+            let applied = adjust_presence(member, total_presence, -(penalty as i64))?;
+            ledger.record(current_time, Some(event.id), ReputationReason::Oubli, ScoreKind::Presence, applied);
+            registration.has_attended = true;
+            registration.attendance_status = AttendanceStatus::Oubli;
+            registration.attendance_delta = applied;
+            event.attended_count = event.attended_count.checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            msg!("Oubli penalty applied: {}", applied);
+        }
+        // Not registered and not present: nothing happens
+        (false, false) => {
+            msg!("No action needed: not registered and not present");
+        }
+    }
+    Ok(())
+}
+
+/// Reverse the presence delta of a recorded attendance and reset the
+/// registration to Unrecorded
+pub fn revert_attendance(
+    total_presence: &mut u64,
+    event: &mut TrackSession,
+    registration: &mut EventRegistration,
+    member: &mut Member,
+    ledger: &mut ReputationLedger,
+    current_time: i64,
+) -> Result<()> {
+    let reversed = adjust_presence(member, total_presence, -registration.attendance_delta)?;
+    ledger.record(current_time, Some(event.id), ReputationReason::AttendanceCorrection, ScoreKind::Presence, reversed);
+
+    // Undo the streak change: a reversed ghosting reconnects the broken streak
+    match registration.attendance_status {
+        AttendanceStatus::Present => {
+            member.current_streak = member.current_streak.saturating_sub(1);
+        }
+        AttendanceStatus::Ghosted => {
+            member.current_streak = member.current_streak.checked_add(registration.broken_streak)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            member.longest_streak = member.longest_streak.max(member.current_streak);
+            registration.broken_streak = 0;
+        }
+        _ => {}
+    }
+
+    if registration.has_attended {
+        event.attended_count = event.attended_count.checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+    registration.has_attended = false;
+    registration.attendance_status = AttendanceStatus::Unrecorded;
+    registration.attendance_delta = 0;
+
+    msg!("Attendance reverted: {} presence", reversed);
+    Ok(())
+}
+
+/// Check that the instruction right before the current one is an ed25519
+/// signature verification of `message` by an organizer of `event`
+/// Returns the signing organizer
+/// The precompile has already verified the signature when this runs; here we
+/// only check that it covered the expected key and message
+fn verify_organizer_signature(
+    instructions: &AccountInfo,
+    event: &TrackSession,
+    message: &[u8],
+) -> Result<Pubkey> {
+    let current_index = load_current_index_checked(instructions)?;
+    require!(current_index > 0, ErrorCode::InvalidCheckInSignature);
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, ErrorCode::InvalidCheckInSignature);
+
+    // Layout: [num_signatures: u8, padding: u8, offsets: 7 x u16, ...data]
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, ErrorCode::InvalidCheckInSignature);
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let public_key_offset = read_u16(6);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+
+    // Signature, key and message must all live in the precompile instruction itself
+    for index_at in [4, 8, 14] {
+        require!(read_u16(index_at) == u16::MAX as usize, ErrorCode::InvalidCheckInSignature);
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32)
+        .ok_or(ErrorCode::InvalidCheckInSignature)?;
+    let signed_message = data.get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidCheckInSignature)?;
+    let signer = Pubkey::try_from(public_key)
+        .map_err(|_| error!(ErrorCode::InvalidCheckInSignature))?;
+    require!(event.is_organizer(&signer), ErrorCode::InvalidCheckInSignature);
+    require!(signed_message == message, ErrorCode::InvalidCheckInSignature);
+    Ok(signer)
+}
+
+/// Apply a signed, already-scaled presence delta to a member and the global total
+/// Negative deltas are capped at the member's current score
+/// Returns the delta actually applied
+fn adjust_presence(member: &mut Member, total_presence: &mut u64, delta: i64) -> Result<i64> {
+    if delta >= 0 {
+        let delta_abs = delta as u64;
+        member.presence_score = member.presence_score.checked_add(delta_abs)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        *total_presence = total_presence.checked_add(delta_abs)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(delta)
+    } else {
+        let actual_delta = delta.unsigned_abs().min(member.presence_score);
+        member.presence_score = member.presence_score.checked_sub(actual_delta)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        *total_presence = total_presence.checked_sub(actual_delta)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        Ok(-(actual_delta as i64))
+    }
+}
+
+/// Apply a signed, already-scaled competence delta to a member and the global total
+/// Negative deltas are capped at the member's current score
+/// Returns the delta actually applied
+fn adjust_competence(member: &mut Member, total_competence: &mut u64, delta: i64) -> Result<i64> {
+    if delta >= 0 {
+        let delta_abs = delta as u64;
+        member.competence_score = member.competence_score.checked_add(delta_abs)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        *total_competence = total_competence.checked_add(delta_abs)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(delta)
+    } else {
+        let actual_delta = delta.unsigned_abs().min(member.competence_score);
+        member.competence_score = member.competence_score.checked_sub(actual_delta)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        *total_competence = total_competence.checked_sub(actual_delta)
+            .ok_or(ErrorCode::SlashingOverflow)?;
+        Ok(-(actual_delta as i64))
+    }
+}
+
+/// Load a Member account passed through `remaining_accounts` and check
+/// that it is the member PDA of `authority`
+fn load_member<'info>(
+    account_info: &'info AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<Account<'info, Member>> {
+    require!(account_info.is_writable, ErrorCode::InvalidRemainingAccounts);
+    let member: Account<'info, Member> = Account::try_from(account_info)?;
+    let expected = Pubkey::create_program_address(
+        &[b"member", authority.as_ref(), &[member.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidRemainingAccounts)?;
+    require_keys_eq!(account_info.key(), expected, ErrorCode::InvalidRemainingAccounts);
+    Ok(member)
+}
+
+/// Load an EventRegistration account passed through `remaining_accounts` and
+/// check that it is the registration PDA of its member for `event_id`
+fn load_registration<'info>(
+    account_info: &'info AccountInfo<'info>,
+    event_id: u64,
+) -> Result<Account<'info, EventRegistration>> {
+    require!(account_info.is_writable, ErrorCode::InvalidRemainingAccounts);
+    let registration: Account<'info, EventRegistration> = Account::try_from(account_info)?;
+    let expected = Pubkey::create_program_address(
+        &[
+            b"registration",
+            event_id.to_le_bytes().as_ref(),
+            registration.member.as_ref(),
+            &[registration.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidRemainingAccounts)?;
+    require_keys_eq!(account_info.key(), expected, ErrorCode::InvalidRemainingAccounts);
+    Ok(registration)
+}
+
+/// Load a ReputationLedger account passed through `remaining_accounts` and
+/// check that it is the ledger PDA of `authority`
+fn load_ledger<'info>(
+    account_info: &'info AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<Account<'info, ReputationLedger>> {
+    require!(account_info.is_writable, ErrorCode::InvalidRemainingAccounts);
+    let ledger: Account<'info, ReputationLedger> = Account::try_from(account_info)?;
+    let expected = Pubkey::create_program_address(
+        &[b"ledger", authority.as_ref(), &[ledger.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidRemainingAccounts)?;
+    require_keys_eq!(account_info.key(), expected, ErrorCode::InvalidRemainingAccounts);
+    Ok(ledger)
+}
+
+/// Load a CompetenceProfile account passed through `remaining_accounts` and
+/// check that it is the profile PDA of `authority`
+fn load_competence_profile<'info>(
+    account_info: &'info AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<Account<'info, CompetenceProfile>> {
+    require!(account_info.is_writable, ErrorCode::InvalidRemainingAccounts);
+    let profile: Account<'info, CompetenceProfile> = Account::try_from(account_info)?;
+    let expected = Pubkey::create_program_address(
+        &[b"competence_profile", authority.as_ref(), &[profile.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidRemainingAccounts)?;
+    require_keys_eq!(account_info.key(), expected, ErrorCode::InvalidRemainingAccounts);
+    Ok(profile)
+}
+
+/// Late penalty for registering or withdrawing `time_until_event` seconds
+/// before the start, following the configured penalty curve
+/// Zero outside the category's late window
+pub fn late_penalty_amount(
+    config: &DaoConfig,
+    schedule: &CategorySchedule,
+    time_until_event: i64,
+) -> Result<u64> {
+    if schedule.late_window <= 0 || time_until_event >= schedule.late_window {
+        return Ok(0);
+    }
+
+    // Share of the late window still remaining before the start, in basis points
+    let remaining_bps = (time_until_event.max(0) as u128 * BPS_DENOMINATOR as u128
+        / schedule.late_window as u128) as u16;
+    let share_bps = match config.late_penalty_curve {
+        LatePenaltyCurve::Cliff => BPS_DENOMINATOR as u16,
+        LatePenaltyCurve::Linear => BPS_DENOMINATOR as u16 - remaining_bps,
+        LatePenaltyCurve::Piecewise => config.late_penalty_steps.iter()
+            .find(|step| remaining_bps < step.remaining_bps)
+            .map_or(0, |step| step.penalty_bps),
+    };
+
+    apply_bps(schedule.scaled_penalty(LATE_PENALTY)?, share_bps)
+}
+
+/// Whether a check-in code expiring at `expires_at` is still valid and
+/// does not outlive CHECK_IN_CODE_TTL
+pub fn check_in_code_valid(current_time: i64, expires_at: i64) -> bool {
+    current_time <= expires_at && expires_at - current_time <= CHECK_IN_CODE_TTL
+}
+
+/// Scale an amount by a multiplier in basis points
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+    let scaled = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / BPS_DENOMINATOR as u128;
+    u64::try_from(scaled).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Weighted median of the review scores, weighted by reviewer competence
+/// Returns the consensus delta scaled by SCALING_FACTOR
+pub fn weighted_median(reviews: &[ReviewerScore]) -> i64 {
+    let mut sorted: Vec<&ReviewerScore> = reviews.iter().collect();
+    sorted.sort_by_key(|r| r.score_delta);
+
+    let total_weight: u128 = sorted.iter().map(|r| r.weight as u128).sum();
+    if total_weight == 0 {
+        return 0;
+    }
+
+    let mut cumulative: u128 = 0;
+    for review in sorted {
+        cumulative += review.weight as u128;
+        if cumulative * 2 >= total_weight {
+            return review.score_delta.saturating_mul(SCALING_FACTOR as i64);
+        }
+    }
+    0
+}
+
+/// Weighted mean of the review scores after dropping the lowest and highest
+/// scores (20% on each side, at least one when there are 3+ reviews)
+/// Returns the consensus delta scaled by SCALING_FACTOR
+pub fn weighted_trimmed_mean(reviews: &[ReviewerScore]) -> i64 {
+    let mut sorted: Vec<&ReviewerScore> = reviews.iter().collect();
+    sorted.sort_by_key(|r| r.score_delta);
+
+    let trim = if sorted.len() >= 3 { (sorted.len() / 5).max(1) } else { 0 };
+    let kept = &sorted[trim..sorted.len() - trim];
+
+    let total_weight: i128 = kept.iter().map(|r| r.weight as i128).sum();
+    if total_weight == 0 {
+        return 0;
+    }
+
+    let weighted_sum: i128 = kept
+        .iter()
+        .map(|r| (r.score_delta as i128) * (r.weight as i128))
+        .sum();
+    let mean = weighted_sum * (SCALING_FACTOR as i128) / total_weight;
+    mean as i64
+}
+
+/// Whether a review score lies further than OUTLIER_THRESHOLD from the
+/// consensus delta (scaled by SCALING_FACTOR)
+pub fn is_outlier(score_delta: i64, consensus: i64) -> bool {
+    let distance = (score_delta as i128) * (SCALING_FACTOR as i128) - consensus as i128;
+    distance.abs() > (OUTLIER_THRESHOLD as i128) * (SCALING_FACTOR as i128)
+}
+
+// ============================================================================
+// ACCOUNT STRUCTURES (PDAs)
+// ============================================================================
+
+/// Global DAO state (singleton)
+#[account]
+#[derive(InitSpace)]
+pub struct State {
+    /// Authority who initialized the DAO
+    pub authority: Pubkey,
+    /// Sum of all active members' presence scores
+    pub total_presence: u64,
+    /// Sum of all active members' competence scores
+    pub total_competence: u64,
+    /// Number of currently active members
+    pub active_members: u8,
+    /// Number of genesis members added (max 3)
+    pub genesis_count: u8,
+    /// Counter for event IDs
+    pub event_counter: u64,
+    /// Counter for proposal IDs
+    pub proposal_counter: u64,
+    /// Counter for competence round IDs
+    pub round_counter: u64,
+    /// Counter for event series IDs
+    pub series_counter: u64,
+    /// Counter for bounty IDs
+    pub bounty_counter: u64,
+    /// Counter for reward distribution IDs
+    pub distribution_counter: u64,
+    /// Tunable DAO parameters
+    pub config: DaoConfig,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Tunable DAO parameters, updated by the DAO authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct DaoConfig {
+    /// Penalty-free withdrawal period after an event is rescheduled (seconds)
+    pub reschedule_grace_period: i64,
+    /// Time after an event ends during which attendance can still be recorded (seconds)
+    pub attendance_grace_period: i64,
+    /// Distinct attendee attestations needed to record a member present
+    pub attestation_threshold: u8,
+    /// Time after finalization during which a ghosting record can be disputed (seconds)
+    pub dispute_window: i64,
+    /// Excused absences allowed per rolling period
+    pub excused_absence_quota: u8,
+    /// Rolling period of the excused absence quota (seconds)
+    pub excused_absence_period: i64,
+    /// Whether excused absences need an organizer's approval
+    pub excused_absence_requires_approval: bool,
+    /// Shape of the late penalty inside the late window
+    pub late_penalty_curve: LatePenaltyCurve,
+    /// Steps of the Piecewise curve, sorted by `remaining_bps`
+    pub late_penalty_steps: [PenaltyStep; 4],
+    /// Attendance reward bonuses by streak length, sorted by `min_streak`
+    pub streak_tiers: [StreakTier; STREAK_TIERS],
+    /// Reward/penalty schedule of each event category, indexed by `EventCategory`
+    pub category_schedules: [CategorySchedule; 4],
+    /// Treasury withdrawals above this amount need a Critical proposal (lamports)
+    pub critical_spend_threshold: u64,
+    /// Per-mint thresholds for treasury token transfers; transfers of a mint
+    /// without an entry always need a Critical proposal
+    pub token_spend_thresholds: [TokenSpendThreshold; TOKEN_SPEND_THRESHOLDS],
+}
+
+/// Reward and penalty rules of an event category
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct CategorySchedule {
+    /// Multiplier applied to ATTENDANCE_REWARD (basis points)
+    pub reward_multiplier_bps: u16,
+    /// Multiplier applied to late, ghosting and oubli penalties (basis points)
+    pub penalty_multiplier_bps: u16,
+    /// Registering or withdrawing closer than this to the start is late (seconds)
+    pub late_window: i64,
+}
+
+/// Attendance reward bonus unlocked at a streak length
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct StreakTier {
+    /// Consecutive attended events needed
+    pub min_streak: u32,
+    /// Multiplier applied to the attendance reward (basis points)
+    pub bonus_bps: u16,
+}
+
+/// Critical spend threshold of an SPL mint held by the treasury
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Default)]
+pub struct TokenSpendThreshold {
+    /// Mint (default pubkey for an unused entry)
+    pub mint: Pubkey,
+    /// Transfers above this amount need a Critical proposal (base units)
+    pub amount: u64,
+}
+
+/// One step of the Piecewise late penalty curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PenaltyStep {
+    /// Applies while less than this share of the late window remains (basis points)
+    pub remaining_bps: u16,
+    /// Share of the full late penalty charged (basis points)
+    pub penalty_bps: u16,
+}
+
+impl CategorySchedule {
+    /// Attendance reward after the category multiplier
+    pub fn scaled_reward(&self, amount: u64) -> Result<u64> {
+        apply_bps(amount, self.reward_multiplier_bps)
+    }
+
+    /// Penalty after the category multiplier
+    pub fn scaled_penalty(&self, amount: u64) -> Result<u64> {
+        apply_bps(amount, self.penalty_multiplier_bps)
+    }
+}
+
+impl Default for DaoConfig {
+    fn default() -> Self {
+        Self {
+            reschedule_grace_period: DEFAULT_RESCHEDULE_GRACE_PERIOD,
+            attendance_grace_period: DEFAULT_ATTENDANCE_GRACE_PERIOD,
+            attestation_threshold: DEFAULT_ATTESTATION_THRESHOLD,
+            dispute_window: DEFAULT_DISPUTE_WINDOW,
+            excused_absence_quota: DEFAULT_EXCUSED_ABSENCE_QUOTA,
+            excused_absence_period: DEFAULT_EXCUSED_ABSENCE_PERIOD,
+            excused_absence_requires_approval: false,
+            late_penalty_curve: LatePenaltyCurve::Linear,
+            late_penalty_steps: [
+                PenaltyStep { remaining_bps: 2_500, penalty_bps: 10_000 },
+                PenaltyStep { remaining_bps: 5_000, penalty_bps: 7_500 },
+                PenaltyStep { remaining_bps: 7_500, penalty_bps: 5_000 },
+                PenaltyStep { remaining_bps: 10_000, penalty_bps: 2_500 },
+            ],
+            streak_tiers: [
+                StreakTier { min_streak: 3, bonus_bps: 11_000 },
+                StreakTier { min_streak: 5, bonus_bps: 12_500 },
+                StreakTier { min_streak: 10, bonus_bps: 15_000 },
+            ],
+            category_schedules: [
+                // Meeting
+                CategorySchedule {
+                    reward_multiplier_bps: 10_000,
+                    penalty_multiplier_bps: 10_000,
+                    late_window: SLOT_DURATION,
+                },
+                // Workshop
+                CategorySchedule {
+                    reward_multiplier_bps: 15_000,
+                    penalty_multiplier_bps: 15_000,
+                    late_window: SLOT_DURATION,
+                },
+                // Hackathon
+                CategorySchedule {
+                    reward_multiplier_bps: 20_000,
+                    penalty_multiplier_bps: 15_000,
+                    late_window: 2 * SLOT_DURATION,
+                },
+                // Mandatory
+                CategorySchedule {
+                    reward_multiplier_bps: 10_000,
+                    penalty_multiplier_bps: 20_000,
+                    late_window: SLOT_DURATION,
+                },
+            ],
+            critical_spend_threshold: DEFAULT_CRITICAL_SPEND_THRESHOLD,
+            token_spend_thresholds: [TokenSpendThreshold::default(); TOKEN_SPEND_THRESHOLDS],
+        }
+    }
+}
+
+impl DaoConfig {
+    /// Reject out-of-range parameters
+    pub fn validate(&self) -> Result<()> {
+        require!(self.reschedule_grace_period >= 0, ErrorCode::InvalidConfig);
+        require!(self.attendance_grace_period >= 0, ErrorCode::InvalidConfig);
+        require!(self.attestation_threshold > 0, ErrorCode::InvalidConfig);
+        require!(self.dispute_window >= 0, ErrorCode::InvalidConfig);
+        require!(
+            self.excused_absence_quota as usize <= MAX_EXCUSED_ABSENCES
+                && self.excused_absence_period >= 0,
+            ErrorCode::InvalidConfig
+        );
+        for schedule in self.category_schedules.iter() {
+            require!(
+                schedule.reward_multiplier_bps <= MAX_MULTIPLIER_BPS
+                    && schedule.penalty_multiplier_bps <= MAX_MULTIPLIER_BPS
+                    && schedule.late_window >= 0,
+                ErrorCode::InvalidConfig
+            );
+        }
+        let mut previous_bps = 0;
+        for step in self.late_penalty_steps.iter() {
+            require!(
+                step.remaining_bps >= previous_bps
+                    && step.remaining_bps as u64 <= BPS_DENOMINATOR
+                    && step.penalty_bps as u64 <= BPS_DENOMINATOR,
+                ErrorCode::InvalidConfig
+            );
+            previous_bps = step.remaining_bps;
+        }
+        let mut previous_streak = 0;
+        for tier in self.streak_tiers.iter() {
+            require!(
+                tier.min_streak > previous_streak
+                    && tier.bonus_bps as u64 >= BPS_DENOMINATOR
+                    && tier.bonus_bps <= MAX_MULTIPLIER_BPS,
+                ErrorCode::InvalidConfig
+            );
+            previous_streak = tier.min_streak;
+        }
+        for (i, threshold) in self.token_spend_thresholds.iter().enumerate() {
+            if threshold.mint == Pubkey::default() {
+                continue;
+            }
+            require!(
+                !self.token_spend_thresholds[..i].iter().any(|other| other.mint == threshold.mint),
+                ErrorCode::InvalidConfig
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether a treasury withdrawal of `amount` needs a Critical proposal
+    pub fn requires_critical(&self, amount: u64) -> bool {
+        amount > self.critical_spend_threshold
+    }
+
+    /// Whether a treasury transfer of `amount` tokens of `mint` needs a
+    /// Critical proposal (any amount of a mint without a threshold does)
+    pub fn requires_critical_token_transfer(&self, mint: &Pubkey, amount: u64) -> bool {
+        let threshold = self.token_spend_thresholds.iter()
+            .find(|threshold| threshold.mint == *mint && *mint != Pubkey::default())
+            .map_or(0, |threshold| threshold.amount);
+        amount > threshold
+    }
+
+    /// Schedule of an event category
+    pub fn schedule(&self, category: EventCategory) -> CategorySchedule {
+        self.category_schedules[category as usize]
+    }
+}
+
+/// Member account
+#[account]
+#[derive(InitSpace)]
+pub struct Member {
+    /// Member's wallet address
+    pub authority: Pubkey,
+    /// Presence score (scaled by SCALING_FACTOR)
+    pub presence_score: u64,
+    /// Competence score (scaled by SCALING_FACTOR)
+    pub competence_score: u64,
+    /// Whether member is currently active
+    pub is_active: bool,
+    /// Whether member is a genesis member
+    pub is_genesis: bool,
+    /// Timestamp when member joined
+    pub joined_at: i64,
+    /// Timestamps of the most recent excused absences (0 for unused slots)
+    pub excused_absences: [i64; MAX_EXCUSED_ABSENCES],
+    /// Consecutive registered-and-attended events since the last ghosting
+    pub current_streak: u32,
+    /// Longest attendance streak ever reached
+    pub longest_streak: u32,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Member {
+    /// Number of excused absences taken within `period` before `current_time`
+    pub fn excused_absences_in_period(&self, current_time: i64, period: i64) -> u8 {
+        self.excused_absences.iter()
+            .filter(|&&taken_at| taken_at > 0 && current_time.saturating_sub(taken_at) < period)
+            .count() as u8
+    }
+
+    /// Record an excused absence, overwriting the oldest entry
+    pub fn record_excused_absence(&mut self, current_time: i64) {
+        if let Some(oldest) = self.excused_absences.iter_mut().min() {
+            *oldest = current_time;
+        }
+    }
+}
+
+/// Event/Track session
+#[account]
+#[derive(InitSpace)]
+pub struct TrackSession {
+    /// Event ID
+    pub id: u64,
+    /// Creator of the event
+    pub creator: Pubkey,
+    /// Start time (unix timestamp)
+    pub start_time: i64,
+    /// End time (unix timestamp)
+    pub end_time: i64,
+    /// Event description
+    #[max_len(256)]
+    pub description: String,
+    /// Whether attendance recording is finalized
+    pub is_finalized: bool,
+    /// Finalization timestamp (0 until finalized)
+    pub finalized_at: i64,
+    /// Number of registered members
+    pub registered_count: u32,
+    /// Number of members who attended
+    pub attended_count: u32,
+    /// Number of registration records ever created (next registration index)
+    pub registration_seq: u32,
+    /// Next registration index to be processed by the ghosting sweep
+    pub sweep_cursor: u32,
+    /// Maximum number of registered members (None for unlimited)
+    pub max_attendees: Option<u32>,
+    /// Members waiting for a seat, in FIFO order
+    #[max_len(32)]
+    pub waitlist: Vec<Pubkey>,
+    /// Whether the event was cancelled
+    pub is_cancelled: bool,
+    /// Withdrawals are penalty-free until this timestamp (set on reschedule)
+    pub penalty_free_until: i64,
+    /// Series the event was spawned from (None for one-off events)
+    pub series_id: Option<u64>,
+    /// Members sharing the organizer privileges with the creator
+    #[max_len(4)]
+    pub co_organizers: Vec<Pubkey>,
+    /// Event category
+    pub category: EventCategory,
+    /// Category schedule, snapshotted at creation so configuration updates
+    /// don't change the rules of an already announced event
+    pub schedule: CategorySchedule,
+    /// Streak bonus tiers, snapshotted at creation like `schedule`
+    pub streak_tiers: [StreakTier; STREAK_TIERS],
+    /// Refundable deposit required to register, in lamports (0 for none)
+    pub deposit_amount: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TrackSession {
+    /// Last timestamp at which attendance can be recorded
+    pub fn recording_closes_at(&self, grace_period: i64) -> Result<i64> {
+        self.end_time.checked_add(grace_period)
+            .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Check that the event can be closed out at `current_time`: neither
+    /// finalized nor cancelled, with its recording window over
+    pub fn check_closable(&self, current_time: i64, grace_period: i64) -> Result<()> {
+        require!(!self.is_finalized, ErrorCode::EventAlreadyFinalized);
+        require!(!self.is_cancelled, ErrorCode::EventCancelled);
+        require!(
+            current_time > self.recording_closes_at(grace_period)?,
+            ErrorCode::RecordingWindowOpen
+        );
+        Ok(())
+    }
+
+    /// Whether the wallet is the creator or a co-organizer
+    pub fn is_organizer(&self, wallet: &Pubkey) -> bool {
+        self.creator == *wallet || self.co_organizers.contains(wallet)
+    }
+
+    /// Attendance reward multiplier for a member reaching `streak` (basis points)
+    pub fn streak_bonus_bps(&self, streak: u32) -> u16 {
+        self.streak_tiers.iter()
+            .filter(|tier| streak >= tier.min_streak)
+            .map(|tier| tier.bonus_bps)
+            .max()
+            .unwrap_or(BPS_DENOMINATOR as u16)
+    }
+
+    /// Whether every seat is taken
+    pub fn is_full(&self) -> bool {
+        self.max_attendees
+            .is_some_and(|max_attendees| self.registered_count >= max_attendees)
+    }
+
+    /// Give the seat freed by a withdrawal to the first waitlisted member,
+    /// whose registration must be `promoted`
+    pub fn promote_from_waitlist(&mut self, promoted: &mut EventRegistration, current_time: i64) -> Result<()> {
+        require!(
+            promoted.is_waitlisted && self.waitlist.first() == Some(&promoted.member),
+            ErrorCode::WaitlistPromotionRequired
+        );
+
+        promoted.is_waitlisted = false;
+        promoted.is_registered = true;
+        promoted.registered_at = current_time;
+        self.waitlist.remove(0);
+        self.registered_count = self.registered_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Move the event to `new_start_time`, keeping its duration, and open a
+    /// penalty-free withdrawal window of `grace_period` from `current_time`
+    pub fn reschedule(&mut self, new_start_time: i64, current_time: i64, grace_period: i64) -> Result<()> {
+        let duration = self.end_time.checked_sub(self.start_time)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.start_time = new_start_time;
+        self.end_time = new_start_time.checked_add(duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.penalty_free_until = current_time.checked_add(grace_period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Whether withdrawing at `current_time` can be charged a late penalty
+    /// (not after a cancellation or inside a reschedule grace period)
+    pub fn withdrawal_penalized(&self, current_time: i64) -> bool {
+        !self.is_cancelled && current_time >= self.penalty_free_until
+    }
+
+    /// Move the ghost sweep past the registration at `index`, which must be
+    /// the next one in order; returns whether every registration was swept
+    pub fn advance_sweep(&mut self, index: u32) -> Result<bool> {
+        require!(index == self.sweep_cursor, ErrorCode::InvalidRemainingAccounts);
+        self.sweep_cursor = self.sweep_cursor.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(self.sweep_cursor == self.registration_seq)
+    }
+
+    /// Whether self check-in is open at `current_time`
+    pub fn check_in_open(&self, current_time: i64) -> Result<bool> {
+        let opens_at = self.start_time.checked_sub(CHECK_IN_WINDOW)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(current_time >= opens_at && current_time <= self.end_time)
+    }
+
+    /// Whether `current_time` falls inside the category's late window
+    pub fn in_late_window(&self, current_time: i64) -> Result<bool> {
+        let time_until_event = self.start_time.checked_sub(current_time)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(time_until_event < self.schedule.late_window)
+    }
+}
+
+/// Escrow of the deposits paid to register for an event
+#[account]
+#[derive(InitSpace)]
+pub struct EventVault {
+    /// Event ID
+    pub event_id: u64,
+    /// Lamports currently held for registrations
+    pub escrowed: u64,
+    /// Total lamports returned to members
+    pub total_refunded: u64,
+    /// Total lamports forfeited to the treasury
+    pub total_forfeited: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Recurring event series, spawning one TrackSession per occurrence
+#[account]
+#[derive(InitSpace)]
+pub struct EventSeries {
+    /// Series ID
+    pub id: u64,
+    /// Creator of the series, organizer of every occurrence
+    pub creator: Pubkey,
+    /// Description copied to every occurrence
+    #[max_len(256)]
+    pub description: String,
+    /// Start time of the first occurrence (unix timestamp)
+    pub first_start_time: i64,
+    /// Duration of each occurrence (seconds)
+    pub duration: i64,
+    /// Interval between occurrences
+    pub recurrence: Recurrence,
+    /// When the series stops
+    pub end: SeriesEnd,
+    /// Capacity of each occurrence (None for unlimited)
+    pub max_attendees: Option<u32>,
+    /// Category of each occurrence
+    pub category: EventCategory,
+    /// Index of the next occurrence that may be spawned
+    pub next_occurrence: u32,
+    /// Number of occurrences spawned so far
+    pub spawned_count: u32,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl EventSeries {
+    /// Start time of the occurrence at `index`
+    pub fn occurrence_start(&self, index: u32) -> Result<i64> {
+        self.recurrence.interval()
+            .checked_mul(index as i64)
+            .and_then(|offset| self.first_start_time.checked_add(offset))
+            .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Index of the first occurrence not yet spawned that starts after `current_time`
+    pub fn next_upcoming_occurrence(&self, current_time: i64) -> Result<u32> {
+        if current_time < self.first_start_time {
+            return Ok(self.next_occurrence);
+        }
+        let elapsed = (current_time - self.first_start_time) / self.recurrence.interval();
+        let first_upcoming = u32::try_from(elapsed + 1)
+            .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        Ok(self.next_occurrence.max(first_upcoming))
+    }
+
+    /// Whether the occurrence at `index`, starting at `start_time`, is beyond the series end
+    pub fn is_past_end(&self, index: u32, start_time: i64) -> bool {
+        match self.end {
+            SeriesEnd::Count(occurrences) => index >= occurrences,
+            SeriesEnd::Until(until) => start_time > until,
+        }
+    }
+}
+
+/// Subscription of a member to an event series
+#[account]
+#[derive(InitSpace)]
+pub struct SeriesSubscription {
+    /// Subscriber's wallet address
+    pub member: Pubkey,
+    /// Series ID
+    pub series_id: u64,
+    /// Subscription timestamp
+    pub subscribed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SeriesSubscription {
+    /// Whether the subscription was made before the occurrence's late window,
+    /// so that auto-registering cannot dodge the late registration penalty
+    pub fn predates_late_window(&self, event: &TrackSession) -> Result<bool> {
+        Ok(!event.in_late_window(self.subscribed_at)?)
+    }
+}
+
+/// Event registration record
+#[account]
+#[derive(InitSpace)]
+pub struct EventRegistration {
+    /// Member's wallet address
+    pub member: Pubkey,
+    /// Event ID
+    pub event_id: u64,
+    /// Whether currently registered
+    pub is_registered: bool,
+    /// Whether currently on the event's waitlist
+    pub is_waitlisted: bool,
+    /// Whether attended the event
+    pub has_attended: bool,
+    /// Recorded attendance outcome
+    pub attendance_status: AttendanceStatus,
+    /// Presence delta applied when attendance was recorded (scaled by SCALING_FACTOR)
+    pub attendance_delta: i64,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// Position of this record among the event's registrations
+    pub index: u32,
+    /// Number of times the member re-registered after withdrawing
+    pub reregister_count: u8,
+    /// Number of peer attestations received
+    pub attestation_count: u8,
+    /// Whether a ghosting dispute was opened for this registration
+    pub is_disputed: bool,
+    /// ID of the dispute proposal, once a dispute was opened
+    pub dispute_proposal_id: Option<u64>,
+    /// Whether an organizer explicitly recorded the member absent
+    pub absence_confirmed: bool,
+    /// Excused absence state
+    pub excuse_status: ExcuseStatus,
+    /// Streak the member had before being recorded as ghosting here
+    pub broken_streak: u32,
+    /// Deposit held in the event vault for this registration (lamports)
+    pub deposit: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl EventRegistration {
+    /// Give up the seat; a declared excuse does not carry over to a later
+    /// re-registration
+    pub fn record_withdrawal(&mut self) {
+        self.is_registered = false;
+        self.excuse_status = ExcuseStatus::NotRequested;
+    }
+
+    /// Count a re-registration after a withdrawal, up to MAX_REREGISTRATIONS
+    pub fn record_reregistration(&mut self, current_time: i64) -> Result<()> {
+        require!(
+            !self.is_registered && !self.is_waitlisted,
+            ErrorCode::AlreadyRegistered
+        );
+        require!(
+            self.attendance_status == AttendanceStatus::Unrecorded,
+            ErrorCode::AttendanceAlreadyRecorded
+        );
+        require!(
+            self.reregister_count < MAX_REREGISTRATIONS,
+            ErrorCode::TooManyReregistrations
+        );
+
+        self.reregister_count = self.reregister_count.checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.registered_at = current_time;
+        Ok(())
+    }
+
+    /// Whether the ghosting dispute for this registration failed, either
+    /// voted down or expired without reaching its passing threshold
+    pub fn dispute_failed(&self, dispute: Option<&Proposal>, current_time: i64) -> bool {
+        dispute.is_some_and(|proposal| {
+            self.dispute_proposal_id == Some(proposal.id) && proposal.has_failed(current_time)
+        })
+    }
+
+    /// Whether the member is ghosted with an absence that was confirmed
+    /// beyond the default sweep: by a failed dispute once one was opened,
+    /// otherwise by an organizer recording it
+    pub fn is_confirmed_absent(&self, dispute: Option<&Proposal>, current_time: i64) -> bool {
+        if self.attendance_status != AttendanceStatus::Ghosted {
+            return false;
+        }
+        if self.is_disputed {
+            self.dispute_failed(dispute, current_time)
+        } else {
+            self.absence_confirmed
+        }
+    }
+
+    /// Whether the ghost sweep must penalize this registration
+    pub fn awaits_ghosting(&self) -> bool {
+        self.is_registered && self.attendance_status == AttendanceStatus::Unrecorded
+    }
+}
+
+/// Peer attestation that a member attended an event
+#[account]
+#[derive(InitSpace)]
+pub struct AttendanceAttestation {
+    /// Event ID
+    pub event_id: u64,
+    /// Attendee vouching for the subject
+    pub witness: Pubkey,
+    /// Member attested present
+    pub subject: Pubkey,
+    /// Attestation timestamp
+    pub attested_at: i64,
+    /// Whether the witness was penalized for this attestation
+    pub is_slashed: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Append-only reputation history of a member (ring buffer)
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationLedger {
+    /// Member's wallet address
+    pub member: Pubkey,
+    /// Most recent entries, oldest overwritten first once full
+    #[max_len(32)]
+    pub entries: Vec<LedgerEntry>,
+    /// Index of the next entry to write
+    pub head: u16,
+    /// Number of entries ever recorded
+    pub total_entries: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReputationLedger {
+    /// Append an entry, overwriting the oldest one when the buffer is full
+    pub fn record(
+        &mut self,
+        timestamp: i64,
+        reference_id: Option<u64>,
+        reason: ReputationReason,
+        score: ScoreKind,
+        delta: i64,
+    ) {
+        let entry = LedgerEntry {
+            timestamp,
+            reference_id,
+            reason,
+            score,
+            delta,
+        };
+        if self.entries.len() < LEDGER_CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.head as usize] = entry;
+        }
+        self.head = ((self.head as usize + 1) % LEDGER_CAPACITY) as u16;
+        self.total_entries = self.total_entries.saturating_add(1);
+    }
+}
+
+/// A single reputation change
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct LedgerEntry {
+    /// Timestamp of the change
+    pub timestamp: i64,
+    /// Event, proposal, competence round or bounty ID the change relates to
+    pub reference_id: Option<u64>,
+    /// Why the score changed
+    pub reason: ReputationReason,
+    /// Which score changed
+    pub score: ScoreKind,
+    /// Signed delta actually applied (scaled by SCALING_FACTOR)
+    pub delta: i64,
+}
+
+/// Registry of configurable skill domains (singleton)
+#[account]
+#[derive(InitSpace)]
+pub struct SkillDomains {
+    /// Domain names, indexed by domain ID
+    #[max_len(8, 32)]
+    pub names: Vec<String>,
+    /// Sum of all members' scores per domain
+    pub totals: [u64; MAX_SKILL_DOMAINS],
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Per-domain competence scores of a member
+#[account]
+#[derive(InitSpace)]
+pub struct CompetenceProfile {
+    /// Member's wallet address
+    pub member: Pubkey,
+    /// Competence score per domain (scaled by SCALING_FACTOR)
+    pub scores: [u64; MAX_SKILL_DOMAINS],
+    /// Number of registry domains seeded into this profile
+    pub domain_count: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// One member's attendance in a `record_attendance_batch` call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct AttendanceEntry {
+    /// Member's wallet address
+    pub wallet: Pubkey,
+    /// Whether the member was present
+    pub was_present: bool,
+}
+
+/// Multi-reviewer competence round for one target member
+#[account]
+#[derive(InitSpace)]
+pub struct CompetenceRound {
+    /// Round ID
+    pub id: u64,
+    /// Wallet address of the member under review
+    pub target: Pubkey,
+    /// Member who opened the round
+    pub opened_by: Pubkey,
+    /// Opening timestamp
+    pub opened_at: i64,
+    /// Timestamp after which no more reviews are accepted
+    pub closes_at: i64,
+    /// How reviewer scores are aggregated
+    pub aggregation: AggregationMethod,
+    /// Whether outlier reviewers lose competence when the round closes
+    pub slash_outliers: bool,
+    /// Skill domain under review (None for the aggregate competence)
+    pub domain: Option<u8>,
+    /// Submitted reviews
+    #[max_len(16)]
+    pub reviews: Vec<ReviewerScore>,
+    /// Whether the round has been closed
+    pub is_closed: bool,
+    /// Competence delta applied to the target (scaled by SCALING_FACTOR)
+    pub applied_delta: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A single reviewer's score in a competence round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct ReviewerScore {
+    /// Reviewer's wallet address
+    pub reviewer: Pubkey,
+    /// Proposed competence delta (whole points)
+    pub score_delta: i64,
+    /// Reviewer's competence score at submission time
+    pub weight: u64,
+    /// Whether the reviewer was flagged as an outlier at close
+    pub is_outlier: bool,
+}
 
 /// Governance proposal
 #[account]
@@ -708,6 +4115,12 @@ pub struct Proposal {
     pub description: String,
     /// Type of proposal (Critical or Operational)
     pub proposal_type: ProposalType,
+    /// Skill domain the proposal is tagged with (None for untagged)
+    pub domain: Option<u8>,
+    /// On-chain action enabled by the proposal once passed
+    pub action: Option<ProposalAction>,
+    /// Whether the action has been carried out
+    pub executed: bool,
     /// Total votes in favor (u128 for voting power)
     pub votes_for: u128,
     /// Total votes against (u128 for voting power)
@@ -724,71 +4137,1982 @@ pub struct Proposal {
     pub bump: u8,
 }
 
-/// Vote record for a member on a proposal
-#[account]
-#[derive(InitSpace)]
-pub struct VoteRecord {
-    /// Voter's wallet address
-    pub voter: Pubkey,
-    /// Proposal ID
-    pub proposal_id: u64,
-    /// Whether voted in support
-    pub support: bool,
-    /// Voting weight used
-    pub weight: u128,
-    /// Whether has voted
-    pub has_voted: bool,
-    /// PDA bump
-    pub bump: u8,
+impl Proposal {
+    /// Whether the votes cast reach the passing threshold of the proposal type
+    pub fn passes(&self) -> bool {
+        match self.proposal_type {
+            // Critical proposals need absolute majority (> 50% of total power)
+            ProposalType::Critical => self.votes_for > self.total_power_snapshot / 2,
+            // Operational proposals need relative majority (for > against)
+            ProposalType::Operational => self.votes_for > self.votes_against,
+        }
+    }
+
+    /// Whether the proposal can no longer pass: rejected, cancelled, or with
+    /// its voting period over short of the threshold but not yet tallied
+    pub fn has_failed(&self, current_time: i64) -> bool {
+        match self.status {
+            ProposalStatus::Rejected | ProposalStatus::Cancelled => true,
+            ProposalStatus::Active => current_time >= self.voting_ends_at && !self.passes(),
+            ProposalStatus::Passed => false,
+        }
+    }
+}
+
+/// DAO treasury vault (singleton), holding SOL as its own lamports
+/// and SPL tokens in associated token accounts it owns
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Total lamports deposited
+    pub total_deposited: u64,
+    /// Total lamports sent out by proposals
+    pub total_withdrawn: u64,
+    /// Number of outgoing transfers (next TreasuryTransfer ID)
+    pub transfer_count: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Record of an outgoing treasury transfer
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryTransfer {
+    /// Transfer ID
+    pub id: u64,
+    /// Proposal that authorized the transfer (None for a reviewer-approved bounty)
+    pub proposal_id: Option<u64>,
+    /// Recipient wallet (token account for token transfers)
+    pub recipient: Pubkey,
+    /// Token mint, None for SOL
+    pub mint: Option<Pubkey>,
+    /// Amount sent (lamports or token base units)
+    pub amount: u64,
+    /// Execution timestamp
+    pub executed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Task posted by the DAO, rewarded in SOL and/or competence
+#[account]
+#[derive(InitSpace)]
+pub struct Bounty {
+    /// Bounty ID
+    pub id: u64,
+    /// Task description
+    #[max_len(256)]
+    pub description: String,
+    /// SOL reward paid from the treasury (lamports, 0 for none)
+    pub reward: u64,
+    /// Competence reward (unscaled, like `update_competence`)
+    pub competence_reward: u64,
+    /// Member allowed to approve the deliverable (None for governance only)
+    pub reviewer: Option<Pubkey>,
+    /// Current status
+    pub status: BountyStatus,
+    /// Member working on the bounty
+    pub claimant: Option<Pubkey>,
+    /// Location of the submitted deliverable
+    #[max_len(200)]
+    pub deliverable_uri: String,
+    /// Hash of the submitted deliverable
+    pub deliverable_hash: [u8; 32],
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Approval timestamp (0 until completed)
+    pub completed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Bounty {
+    /// Check that the bounty is open and can be taken by `claimant`, who
+    /// must not be its reviewer
+    pub fn check_claimable(&self, claimant: &Pubkey) -> Result<()> {
+        require!(self.status == BountyStatus::Open, ErrorCode::BountyNotOpen);
+        require!(self.reviewer != Some(*claimant), ErrorCode::ReviewerCannotClaim);
+        Ok(())
+    }
+
+    /// Whether approval by `approver` must be backed by a passed proposal:
+    /// always unless they are the reviewer and the reward is below the
+    /// critical spend threshold
+    pub fn requires_proposal(&self, approver: &Pubkey, config: &DaoConfig) -> bool {
+        self.reviewer != Some(*approver) || config.requires_critical(self.reward)
+    }
+
+    /// Mark the bounty completed and apply its competence reward to the
+    /// claimant, recorded in their ledger against the bounty ID; returns
+    /// the applied (scaled) competence delta
+    pub fn complete(
+        &mut self,
+        member: &mut Member,
+        total_competence: &mut u64,
+        ledger: &mut ReputationLedger,
+        current_time: i64,
+    ) -> Result<i64> {
+        let scaled_reward = self.competence_reward.checked_mul(SCALING_FACTOR)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let scaled_reward = i64::try_from(scaled_reward)
+            .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        let applied = adjust_competence(member, total_competence, scaled_reward)?;
+        ledger.record(current_time, Some(self.id), ReputationReason::Bounty, ScoreKind::Competence, applied);
+
+        self.status = BountyStatus::Completed;
+        self.completed_at = current_time;
+        Ok(applied)
+    }
+
+    /// Proposal action approving the current claimant's submitted deliverable
+    pub fn approval_action(&self) -> Result<ProposalAction> {
+        let claimant = self.claimant.ok_or(ErrorCode::BountyNotClaimed)?;
+        Ok(ProposalAction::ApproveBounty {
+            bounty_id: self.id,
+            claimant,
+            deliverable_hash: self.deliverable_hash,
+        })
+    }
+}
+
+/// Reward distribution epoch, holding the distributed SOL until claimed
+#[account]
+#[derive(InitSpace)]
+pub struct Distribution {
+    /// Distribution ID
+    pub id: u64,
+    /// Proposal that funded the distribution
+    pub proposal_id: u64,
+    /// Merkle root of the (distribution ID, claimant, amount) leaves
+    pub merkle_root: [u8; 32],
+    /// Formula the shares were computed with
+    pub formula: DistributionFormula,
+    /// Lamports funded from the treasury
+    pub total_amount: u64,
+    /// Lamports claimed so far
+    pub claimed_amount: u64,
+    /// Number of claims
+    pub claim_count: u32,
+    /// Last timestamp at which shares can be claimed
+    pub claim_deadline: i64,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Whether the unclaimed remainder went back to the treasury
+    pub is_reclaimed: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Distribution {
+    /// Close the distribution once its claim deadline has passed, taking
+    /// the unclaimed remainder off the treasury's withdrawn total; returns
+    /// the lamports to move back to the treasury
+    pub fn reclaim(&mut self, treasury: &mut Treasury, current_time: i64) -> Result<u64> {
+        require!(!self.is_reclaimed, ErrorCode::DistributionAlreadyReclaimed);
+        require!(current_time > self.claim_deadline, ErrorCode::ClaimPeriodOpen);
+
+        let unclaimed = self.total_amount.checked_sub(self.claimed_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        treasury.total_withdrawn = treasury.total_withdrawn.checked_sub(unclaimed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.is_reclaimed = true;
+        Ok(unclaimed)
+    }
+}
+
+/// Receipt of a member's distribution claim
+#[account]
+#[derive(InitSpace)]
+pub struct DistributionClaim {
+    /// Distribution ID
+    pub distribution_id: u64,
+    /// Claimant wallet
+    pub claimant: Pubkey,
+    /// Lamports claimed
+    pub amount: u64,
+    /// Claim timestamp
+    pub claimed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Vote record for a member on a proposal
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    /// Voter's wallet address
+    pub voter: Pubkey,
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Whether voted in support
+    pub support: bool,
+    /// Voting weight used
+    pub weight: u128,
+    /// Whether has voted
+    pub has_voted: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ============================================================================
+// ENUMS
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalType {
+    /// Critical proposals (cooptation, ban) - need absolute majority
+    Critical,
+    /// Operational proposals (subjects, dates) - need relative majority
+    Operational,
+}
+
+/// On-chain action carried by a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    /// Cancel an event
+    CancelEvent { event_id: u64 },
+    /// Move an event to a new start time
+    RescheduleEvent { event_id: u64, new_start_time: i64 },
+    /// Overturn a ghosting record: the member was present
+    AttendanceDispute { event_id: u64, member: Pubkey },
+    /// Send SOL from the treasury
+    TreasuryWithdrawal { recipient: Pubkey, amount: u64 },
+    /// Send SPL tokens from the treasury to a token account
+    TokenTransfer { mint: Pubkey, destination: Pubkey, amount: u64 },
+    /// Approve a submitted bounty
+    ApproveBounty { bounty_id: u64, claimant: Pubkey, deliverable_hash: [u8; 32] },
+    /// Fund a reward distribution from the treasury, the leaves under
+    /// `merkle_root` being built for `distribution_id`
+    FundDistribution {
+        distribution_id: u64,
+        merkle_root: [u8; 32],
+        amount: u64,
+        claim_deadline: i64,
+        formula: DistributionFormula,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalStatus {
+    /// Voting is ongoing
+    Active,
+    /// Proposal passed
+    Passed,
+    /// Proposal rejected
+    Rejected,
+    /// Proposal cancelled
+    Cancelled,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReputationReason {
+    /// Registered less than 24h before the event
+    LateRegistration,
+    /// Withdrew less than 24h before the event
+    LateWithdrawal,
+    /// Registered but absent
+    Ghosting,
+    /// Present but not registered
+    Oubli,
+    /// Registered and present
+    Attendance,
+    /// Competence peer review
+    PeerReview,
+    /// Member banned
+    Ban,
+    /// Initial genesis scores
+    Genesis,
+    /// Initial cooptation scores
+    Coopt,
+    /// Reversal of a previously recorded attendance
+    AttendanceCorrection,
+    /// Attested a member later shown absent
+    FalseAttestation,
+    /// Completed bounty
+    Bounty,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DistributionFormula {
+    /// Voting weight: presence share × competence share
+    VotingWeight,
+    /// Presence score only
+    Presence,
+    /// Competence score only
+    Competence,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BountyStatus {
+    /// Waiting for a claimant
+    Open,
+    /// Claimed, deliverable not submitted yet
+    Claimed,
+    /// Deliverable submitted, waiting for approval
+    Submitted,
+    /// Approved and rewarded
+    Completed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AttendanceStatus {
+    /// Attendance not recorded yet
+    Unrecorded,
+    /// Registered and present
+    Present,
+    /// Registered but absent
+    Ghosted,
+    /// Present but not registered
+    Oubli,
+    /// Registered, absent with an approved excuse
+    Excused,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ExcuseStatus {
+    /// No excused absence declared
+    NotRequested,
+    /// Declared, waiting for organizer approval
+    Pending,
+    /// Approved: absence is not penalized
+    Approved,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ScoreKind {
+    /// Member presence score
+    Presence,
+    /// Member aggregate competence score
+    Competence,
+    /// Member competence score in one skill domain
+    DomainCompetence(u8),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EventCategory {
+    /// Short meeting or standup
+    Meeting,
+    /// Hands-on workshop
+    Workshop,
+    /// Multi-day hackathon
+    Hackathon,
+    /// Attendance expected from every member
+    Mandatory,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum LatePenaltyCurve {
+    /// Full penalty anywhere inside the late window
+    Cliff,
+    /// Grows linearly from zero at the window edge to the full penalty at the start
+    Linear,
+    /// Steps defined in `DaoConfig::late_penalty_steps`
+    Piecewise,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Recurrence {
+    /// Every 7 days
+    Weekly,
+    /// Every 14 days
+    Biweekly,
+}
+
+impl Recurrence {
+    /// Time between two occurrences (seconds)
+    pub fn interval(&self) -> i64 {
+        match self {
+            Recurrence::Weekly => 7 * SLOT_DURATION,
+            Recurrence::Biweekly => 14 * SLOT_DURATION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SeriesEnd {
+    /// Stop after this many occurrences
+    Count(u32),
+    /// Stop after the last occurrence starting at or before this timestamp
+    Until(i64),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AggregationMethod {
+    /// Competence-weighted median of reviewer scores
+    WeightedMedian,
+    /// Competence-weighted mean after trimming the extremes
+    TrimmedMean,
+}
+
+// ============================================================================
+// ACCOUNT CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + State::INIT_SPACE,
+        seeds = [b"state"],
+        bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddGenesisMember<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Member::INIT_SPACE,
+        seeds = [b"member", member_authority.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ReputationLedger::INIT_SPACE,
+        seeds = [b"ledger", member_authority.key().as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+    
+    /// The wallet address of the new member
+    /// CHECK: This is the authority for the new member account
+    pub member_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump,
+        constraint = state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub state: Account<'info, State>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateEvent<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + TrackSession::INIT_SPACE,
+        seeds = [b"track", state.event_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub event: Account<'info, TrackSession>,
+    
+    #[account(
+        seeds = [b"member", creator.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenEventVault<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.is_organizer(&organizer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + EventVault::INIT_SPACE,
+        seeds = [b"event_vault", event.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterForEvent<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EventRegistration::INIT_SPACE,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    /// Event vault, required when the event takes a deposit
+    #[account(
+        mut,
+        seeds = [b"event_vault", event.id.to_le_bytes().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Option<Account<'info, EventVault>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromEvent<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = registration.bump,
+        constraint = registration.member == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+    
+    /// Registration of the first waitlisted member, required when the waitlist is not empty
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), promoted_registration.member.as_ref()],
+        bump = promoted_registration.bump
+    )]
+    pub promoted_registration: Option<Account<'info, EventRegistration>>,
+
+    /// Event vault, required when the event takes a deposit
+    #[account(
+        mut,
+        seeds = [b"event_vault", event.id.to_le_bytes().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Option<Account<'info, EventVault>>,
+
+    /// Treasury receiving a deposit forfeited by a late withdrawal
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PreviewLatePenalty<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        seeds = [b"member", member.authority.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct DeclareExcusedAbsence<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveExcusedAbsence<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.is_organizer(&organizer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), registration.member.as_ref()],
+        bump = registration.bump,
+        constraint = registration.member != organizer.key() @ ErrorCode::OrganizerSelfAttendance
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"member", registration.member.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        seeds = [b"member", organizer.key().as_ref()],
+        bump = organizer_member.bump,
+        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub organizer_member: Account<'info, Member>,
+
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateEventSeries<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + EventSeries::INIT_SPACE,
+        seeds = [b"series", state.series_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        seeds = [b"member", creator.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SpawnSeriesEvent<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"series", series.id.to_le_bytes().as_ref()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + TrackSession::INIT_SPACE,
+        seeds = [b"track", state.event_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubscribeToSeries<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"series", series.id.to_le_bytes().as_ref()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, EventSeries>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SeriesSubscription::INIT_SPACE,
+        seeds = [b"series_subscription", series.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SeriesSubscription>,
+
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnsubscribeFromSeries<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"series_subscription", subscription.series_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, SeriesSubscription>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApplySeriesSubscription<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        seeds = [
+            b"series_subscription",
+            subscription.series_id.to_le_bytes().as_ref(),
+            subscription.member.as_ref()
+        ],
+        bump = subscription.bump,
+        constraint = event.series_id == Some(subscription.series_id) @ ErrorCode::SeriesMismatch
+    )]
+    pub subscription: Account<'info, SeriesSubscription>,
+
+    #[account(
+        seeds = [b"member", subscription.member.as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + EventRegistration::INIT_SPACE,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), subscription.member.as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReregisterForEvent<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = registration.bump,
+        constraint = registration.member == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    /// Event vault, required when the event takes a deposit
+    #[account(
+        mut,
+        seeds = [b"event_vault", event.id.to_le_bytes().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Option<Account<'info, EventVault>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordAttendance<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.is_organizer(&organizer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", member_authority.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    /// CHECK: This is the authority of the member being recorded
+    #[account(constraint = member_authority.key() != organizer.key() @ ErrorCode::OrganizerSelfAttendance)]
+    pub member_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", member_authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), member_authority.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+    
+    #[account(
+        seeds = [b"member", organizer.key().as_ref()],
+        bump = organizer_member.bump,
+        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub organizer_member: Account<'info, Member>,
+    
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RecordOubli<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.is_organizer(&organizer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"member", member_authority.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+
+    /// CHECK: This is the authority of the member being recorded
+    #[account(constraint = member_authority.key() != organizer.key() @ ErrorCode::OrganizerSelfAttendance)]
+    pub member_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", member_authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + EventRegistration::INIT_SPACE,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), member_authority.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        seeds = [b"member", organizer.key().as_ref()],
+        bump = organizer_member.bump,
+        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub organizer_member: Account<'info, Member>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordAttendanceBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.is_organizer(&organizer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        seeds = [b"member", organizer.key().as_ref()],
+        bump = organizer_member.bump,
+        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub organizer_member: Account<'info, Member>,
+
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepGhosts<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseOutEvent<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleEventDeposit<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), registration.member.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"event_vault", event.id.to_le_bytes().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, EventVault>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Dispute of the member's ghosting, required to forfeit a disputed deposit
+    #[account(
+        seeds = [b"proposal", dispute_proposal.id.to_le_bytes().as_ref()],
+        bump = dispute_proposal.bump
+    )]
+    pub dispute_proposal: Option<Account<'info, Proposal>>,
+
+    /// CHECK: Wallet of the registered member, receiving refunds
+    #[account(
+        mut,
+        constraint = member_wallet.key() == registration.member @ ErrorCode::Unauthorized
+    )]
+    pub member_wallet: UncheckedAccount<'info>,
+
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SelfCheckIn<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = registration.bump,
+        constraint = registration.member == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    /// CHECK: Instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestAttendance<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        seeds = [b"member", witness.key().as_ref()],
+        bump = witness_member.bump,
+        constraint = witness_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub witness_member: Account<'info, Member>,
+
+    #[account(
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), witness.key().as_ref()],
+        bump = witness_registration.bump,
+        constraint = witness_registration.attendance_status == AttendanceStatus::Present
+            @ ErrorCode::WitnessNotPresent
+    )]
+    pub witness_registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), subject_registration.member.as_ref()],
+        bump = subject_registration.bump,
+        constraint = subject_registration.member != witness.key() @ ErrorCode::CannotAttestSelf
+    )]
+    pub subject_registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"member", subject_registration.member.as_ref()],
+        bump = subject_member.bump,
+        constraint = subject_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub subject_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", subject_registration.member.as_ref()],
+        bump = subject_ledger.bump
+    )]
+    pub subject_ledger: Account<'info, ReputationLedger>,
+
+    #[account(
+        init,
+        payer = witness,
+        space = 8 + AttendanceAttestation::INIT_SPACE,
+        seeds = [
+            b"attestation",
+            event.id.to_le_bytes().as_ref(),
+            witness.key().as_ref(),
+            subject_registration.member.as_ref()
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, AttendanceAttestation>,
+
+    #[account(mut)]
+    pub witness: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SlashFalseAttestation<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"attestation",
+            event.id.to_le_bytes().as_ref(),
+            attestation.witness.as_ref(),
+            attestation.subject.as_ref()
+        ],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, AttendanceAttestation>,
+
+    #[account(
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), attestation.subject.as_ref()],
+        bump = subject_registration.bump
+    )]
+    pub subject_registration: Account<'info, EventRegistration>,
+
+    /// Failed dispute of the subject's ghosting, required unless an
+    /// organizer recorded the absence
+    #[account(
+        seeds = [b"proposal", dispute_proposal.id.to_le_bytes().as_ref()],
+        bump = dispute_proposal.bump
+    )]
+    pub dispute_proposal: Option<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds = [b"member", attestation.witness.as_ref()],
+        bump = witness_member.bump
+    )]
+    pub witness_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", attestation.witness.as_ref()],
+        bump = witness_ledger.bump
+    )]
+    pub witness_ledger: Account<'info, ReputationLedger>,
+
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenAttendanceDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", state.proposal_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveAttendanceDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"registration", event.id.to_le_bytes().as_ref(), registration.member.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, EventRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"member", registration.member.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", registration.member.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddCoOrganizer<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.creator == creator.key() @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    #[account(
+        seeds = [b"member", co_organizer.key().as_ref()],
+        bump = co_organizer_member.bump,
+        constraint = co_organizer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub co_organizer_member: Account<'info, Member>,
+
+    /// CHECK: This is the wallet of the co-organizer being added
+    pub co_organizer: UncheckedAccount<'info>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveCoOrganizer<'info> {
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.creator == creator.key() @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ManageEvent<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, TrackSession>,
+
+    /// Passed proposal authorizing the change, required unless signed by the creator
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeEvent<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"track", event.id.to_le_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.is_organizer(&organizer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, TrackSession>,
+    
+    #[account(
+        seeds = [b"member", organizer.key().as_ref()],
+        bump = organizer_member.bump,
+        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub organizer_member: Account<'info, Member>,
+    
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCompetence<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", target_authority.key().as_ref()],
+        bump = target_member.bump
+    )]
+    pub target_member: Account<'info, Member>,
+    
+    /// CHECK: This is the authority of the target member
+    pub target_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", target_authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+    
+    #[account(
+        seeds = [b"member", reviewer.key().as_ref()],
+        bump = reviewer_member.bump,
+        constraint = reviewer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub reviewer_member: Account<'info, Member>,
+    
+    pub reviewer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSkillDomains<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump,
+        constraint = state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SkillDomains::INIT_SPACE,
+        seeds = [b"skill_domains"],
+        bump
+    )]
+    pub skill_domains: Account<'info, SkillDomains>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddSkillDomain<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump,
+        constraint = state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Account<'info, SkillDomains>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateCompetenceProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Account<'info, SkillDomains>,
+
+    #[account(
+        seeds = [b"member", member_authority.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+
+    /// CHECK: This is the authority of the member owning the profile
+    pub member_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CompetenceProfile::INIT_SPACE,
+        seeds = [b"competence_profile", member_authority.key().as_ref()],
+        bump
+    )]
+    pub competence_profile: Account<'info, CompetenceProfile>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
-// ============================================================================
-// ENUMS
-// ============================================================================
+#[derive(Accounts)]
+pub struct SyncCompetenceProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Account<'info, SkillDomains>,
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ProposalType {
-    /// Critical proposals (cooptation, ban) - need absolute majority
-    Critical,
-    /// Operational proposals (subjects, dates) - need relative majority
-    Operational,
+    #[account(
+        seeds = [b"member", competence_profile.member.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"competence_profile", competence_profile.member.as_ref()],
+        bump = competence_profile.bump
+    )]
+    pub competence_profile: Account<'info, CompetenceProfile>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ProposalStatus {
-    /// Voting is ongoing
-    Active,
-    /// Proposal passed
-    Passed,
-    /// Proposal rejected
-    Rejected,
-    /// Proposal cancelled
-    Cancelled,
+#[derive(Accounts)]
+pub struct UpdateDomainCompetence<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Account<'info, SkillDomains>,
+
+    #[account(
+        seeds = [b"member", target_authority.key().as_ref()],
+        bump = target_member.bump
+    )]
+    pub target_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"competence_profile", target_authority.key().as_ref()],
+        bump = competence_profile.bump
+    )]
+    pub competence_profile: Account<'info, CompetenceProfile>,
+
+    /// CHECK: This is the authority of the target member
+    pub target_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", target_authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    #[account(
+        seeds = [b"member", reviewer.key().as_ref()],
+        bump = reviewer_member.bump,
+        constraint = reviewer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub reviewer_member: Account<'info, Member>,
+
+    pub reviewer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenCompetenceRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init,
+        payer = opener,
+        space = 8 + CompetenceRound::INIT_SPACE,
+        seeds = [b"competence_round", state.round_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub round: Account<'info, CompetenceRound>,
+
+    #[account(
+        seeds = [b"member", target_authority.key().as_ref()],
+        bump = target_member.bump
+    )]
+    pub target_member: Account<'info, Member>,
+
+    /// CHECK: This is the authority of the member under review
+    pub target_authority: UncheckedAccount<'info>,
+
+    /// Required for domain rounds
+    #[account(
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Option<Account<'info, SkillDomains>>,
+
+    #[account(
+        seeds = [b"member", opener.key().as_ref()],
+        bump = opener_member.bump,
+        constraint = opener_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub opener_member: Account<'info, Member>,
+
+    #[account(mut)]
+    pub opener: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitCompetenceReview<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"competence_round", round.id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, CompetenceRound>,
+
+    #[account(
+        seeds = [b"member", reviewer.key().as_ref()],
+        bump = reviewer_member.bump,
+        constraint = reviewer_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub reviewer_member: Account<'info, Member>,
+
+    /// Required for domain rounds
+    #[account(
+        seeds = [b"competence_profile", reviewer.key().as_ref()],
+        bump = reviewer_profile.bump
+    )]
+    pub reviewer_profile: Option<Account<'info, CompetenceProfile>>,
+
+    pub reviewer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCompetenceRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"competence_round", round.id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, CompetenceRound>,
+
+    #[account(
+        mut,
+        seeds = [b"member", round.target.as_ref()],
+        bump = target_member.bump
+    )]
+    pub target_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", round.target.as_ref()],
+        bump = target_ledger.bump
+    )]
+    pub target_ledger: Account<'info, ReputationLedger>,
+
+    /// Required for domain rounds
+    #[account(
+        mut,
+        seeds = [b"competence_profile", round.target.as_ref()],
+        bump = target_profile.bump
+    )]
+    pub target_profile: Option<Account<'info, CompetenceProfile>>,
+
+    /// Required for domain rounds
+    #[account(
+        mut,
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Option<Account<'info, SkillDomains>>,
+
+    pub closer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", state.proposal_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"member", proposer.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    /// Required for domain-tagged proposals
+    #[account(
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Option<Account<'info, SkillDomains>>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
-// ============================================================================
-// ACCOUNT CONTEXTS
-// ============================================================================
+#[derive(Accounts)]
+pub struct Vote<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, State>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"member", voter.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote", proposal.id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// Required for domain-tagged proposals
+    #[account(
+        seeds = [b"competence_profile", voter.key().as_ref()],
+        bump = competence_profile.bump
+    )]
+    pub competence_profile: Option<Account<'info, CompetenceProfile>>,
+
+    /// Required for domain-tagged proposals
+    #[account(
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Option<Account<'info, SkillDomains>>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
+pub struct ExecuteProposal<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + State::INIT_SPACE,
         seeds = [b"state"],
-        bump
+        bump = state.bump
     )]
     pub state: Account<'info, State>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
     
-    pub system_program: Program<'info, System>,
+    pub executor: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct AddGenesisMember<'info> {
+pub struct BanMember<'info> {
     #[account(
         mut,
         seeds = [b"state"],
@@ -797,26 +6121,50 @@ pub struct AddGenesisMember<'info> {
     pub state: Account<'info, State>,
     
     #[account(
-        init,
-        payer = authority,
-        space = 8 + Member::INIT_SPACE,
-        seeds = [b"member", member_authority.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"member", target_authority.key().as_ref()],
+        bump = target_member.bump
     )]
-    pub member: Account<'info, Member>,
+    pub target_member: Account<'info, Member>,
     
-    /// The wallet address of the new member
-    /// CHECK: This is the authority for the new member account
-    pub member_authority: UncheckedAccount<'info>,
+    /// CHECK: This is the authority of the member to ban
+    pub target_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"ledger", target_authority.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    /// Competence profile of the banned member, required when they have one
+    #[account(
+        mut,
+        seeds = [b"competence_profile", target_authority.key().as_ref()],
+        bump = competence_profile.bump
+    )]
+    pub competence_profile: Option<Account<'info, CompetenceProfile>>,
+
+    /// Required when the banned member has a competence profile
+    #[account(
+        mut,
+        seeds = [b"skill_domains"],
+        bump = skill_domains.bump
+    )]
+    pub skill_domains: Option<Account<'info, SkillDomains>>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"member", executor.key().as_ref()],
+        bump = executor_member.bump,
+        constraint = executor_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub executor_member: Account<'info, Member>,
     
-    pub system_program: Program<'info, System>,
+    pub executor: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CreateEvent<'info> {
+pub struct CooptMember<'info> {
     #[account(
         mut,
         seeds = [b"state"],
@@ -826,347 +6174,483 @@ pub struct CreateEvent<'info> {
     
     #[account(
         init,
-        payer = creator,
-        space = 8 + TrackSession::INIT_SPACE,
-        seeds = [b"track", state.event_counter.to_le_bytes().as_ref()],
+        payer = sponsor,
+        space = 8 + Member::INIT_SPACE,
+        seeds = [b"member", new_member_authority.key().as_ref()],
         bump
     )]
-    pub event: Account<'info, TrackSession>,
+    pub new_member: Account<'info, Member>,
     
     #[account(
-        seeds = [b"member", creator.key().as_ref()],
-        bump = member.bump,
-        constraint = member.is_active @ ErrorCode::MemberNotActive
+        init,
+        payer = sponsor,
+        space = 8 + ReputationLedger::INIT_SPACE,
+        seeds = [b"ledger", new_member_authority.key().as_ref()],
+        bump
     )]
-    pub member: Account<'info, Member>,
+    pub ledger: Account<'info, ReputationLedger>,
+    
+    /// CHECK: This is the authority for the new member
+    pub new_member_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"member", sponsor.key().as_ref()],
+        bump = sponsor_member.bump,
+        constraint = sponsor_member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub sponsor_member: Account<'info, Member>,
     
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub sponsor: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RegisterForEvent<'info> {
+pub struct InitializeTreasury<'info> {
+    #[account(
+        seeds = [b"state"],
+        bump = state.bump,
+        constraint = state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
     #[account(
         mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromTreasury<'info> {
+    #[account(
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
         mut,
-        seeds = [b"track", event.id.to_le_bytes().as_ref()],
-        bump = event.bump
+        seeds = [b"treasury"],
+        bump = treasury.bump
     )]
-    pub event: Account<'info, TrackSession>,
-    
+    pub treasury: Account<'info, Treasury>,
+
     #[account(
         mut,
-        seeds = [b"member", authority.key().as_ref()],
-        bump = member.bump,
-        constraint = member.is_active @ ErrorCode::MemberNotActive
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
     )]
-    pub member: Account<'info, Member>,
-    
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: Recipient named by the proposal action, checked in the handler
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
     #[account(
         init,
-        payer = authority,
-        space = 8 + EventRegistration::INIT_SPACE,
-        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        payer = executor,
+        space = 8 + TreasuryTransfer::INIT_SPACE,
+        seeds = [b"treasury_transfer", treasury.transfer_count.to_le_bytes().as_ref()],
         bump
     )]
-    pub registration: Account<'info, EventRegistration>,
-    
+    pub transfer_record: Account<'info, TreasuryTransfer>,
+
     #[account(mut)]
-    pub authority: Signer<'info>,
-    
+    pub executor: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct WithdrawFromEvent<'info> {
+pub struct CreateTreasuryTokenAccount<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryTokens<'info> {
     #[account(
-        mut,
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
         mut,
-        seeds = [b"track", event.id.to_le_bytes().as_ref()],
-        bump = event.bump
+        seeds = [b"treasury"],
+        bump = treasury.bump
     )]
-    pub event: Account<'info, TrackSession>,
-    
+    pub treasury: Account<'info, Treasury>,
+
     #[account(
         mut,
-        seeds = [b"member", authority.key().as_ref()],
-        bump = member.bump,
-        constraint = member.is_active @ ErrorCode::MemberNotActive
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
     )]
-    pub member: Account<'info, Member>,
-    
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
     #[account(
         mut,
-        seeds = [b"registration", event.id.to_le_bytes().as_ref(), authority.key().as_ref()],
-        bump = registration.bump,
-        constraint = registration.member == authority.key() @ ErrorCode::Unauthorized
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
     )]
-    pub registration: Account<'info, EventRegistration>,
-    
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + TreasuryTransfer::INIT_SPACE,
+        seeds = [b"treasury_transfer", treasury.transfer_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub transfer_record: Account<'info, TreasuryTransfer>,
+
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub executor: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RecordAttendance<'info> {
+pub struct CreateBounty<'info> {
     #[account(
         mut,
         seeds = [b"state"],
-        bump = state.bump
+        bump = state.bump,
+        constraint = state.authority == authority.key() @ ErrorCode::Unauthorized
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
-        mut,
-        seeds = [b"track", event.id.to_le_bytes().as_ref()],
-        bump = event.bump,
-        constraint = event.creator == organizer.key() @ ErrorCode::Unauthorized
+        init,
+        payer = authority,
+        space = 8 + Bounty::INIT_SPACE,
+        seeds = [b"bounty", state.bounty_counter.to_le_bytes().as_ref()],
+        bump
     )]
-    pub event: Account<'info, TrackSession>,
-    
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimBounty<'info> {
     #[account(
-        mut,
-        seeds = [b"member", member_authority.key().as_ref()],
-        bump = member.bump
+        seeds = [b"state"],
+        bump = state.bump
     )]
-    pub member: Account<'info, Member>,
-    
-    /// CHECK: This is the authority of the member being recorded
-    pub member_authority: UncheckedAccount<'info>,
-    
+    pub state: Account<'info, State>,
+
     #[account(
         mut,
-        seeds = [b"registration", event.id.to_le_bytes().as_ref(), member_authority.key().as_ref()],
-        bump = registration.bump
+        seeds = [b"bounty", bounty.id.to_le_bytes().as_ref()],
+        bump = bounty.bump
     )]
-    pub registration: Account<'info, EventRegistration>,
-    
+    pub bounty: Account<'info, Bounty>,
+
     #[account(
-        seeds = [b"member", organizer.key().as_ref()],
-        bump = organizer_member.bump,
-        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
-    )]
-    pub organizer_member: Account<'info, Member>,
-    
-    #[account(mut)]
-    pub organizer: Signer<'info>,
+        seeds = [b"member", claimant.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_active @ ErrorCode::MemberNotActive
+    )]
+    pub member: Account<'info, Member>,
+
+    pub claimant: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct FinalizeEvent<'info> {
+pub struct SubmitBounty<'info> {
     #[account(
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
         mut,
-        seeds = [b"track", event.id.to_le_bytes().as_ref()],
-        bump = event.bump,
-        constraint = event.creator == organizer.key() @ ErrorCode::Unauthorized
-    )]
-    pub event: Account<'info, TrackSession>,
-    
-    #[account(
-        seeds = [b"member", organizer.key().as_ref()],
-        bump = organizer_member.bump,
-        constraint = organizer_member.is_active @ ErrorCode::MemberNotActive
+        seeds = [b"bounty", bounty.id.to_le_bytes().as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.claimant == Some(claimant.key()) @ ErrorCode::Unauthorized
     )]
-    pub organizer_member: Account<'info, Member>,
-    
-    pub organizer: Signer<'info>,
+    pub bounty: Account<'info, Bounty>,
+
+    pub claimant: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateCompetence<'info> {
+pub struct ReleaseBounty<'info> {
     #[account(
-        mut,
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
         mut,
-        seeds = [b"member", target_authority.key().as_ref()],
-        bump = target_member.bump
-    )]
-    pub target_member: Account<'info, Member>,
-    
-    /// CHECK: This is the authority of the target member
-    pub target_authority: UncheckedAccount<'info>,
-    
-    #[account(
-        seeds = [b"member", reviewer.key().as_ref()],
-        bump = reviewer_member.bump,
-        constraint = reviewer_member.is_active @ ErrorCode::MemberNotActive
+        seeds = [b"bounty", bounty.id.to_le_bytes().as_ref()],
+        bump = bounty.bump
     )]
-    pub reviewer_member: Account<'info, Member>,
-    
-    pub reviewer: Signer<'info>,
+    pub bounty: Account<'info, Bounty>,
+
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CreateProposal<'info> {
+pub struct ApproveBounty<'info> {
     #[account(
         mut,
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
-        init,
-        payer = proposer,
-        space = 8 + Proposal::INIT_SPACE,
-        seeds = [b"proposal", state.proposal_counter.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [b"bounty", bounty.id.to_le_bytes().as_ref()],
+        bump = bounty.bump
     )]
-    pub proposal: Account<'info, Proposal>,
-    
+    pub bounty: Account<'info, Bounty>,
+
+    /// Passed proposal approving the bounty, required unless signed by the reviewer
     #[account(
-        seeds = [b"member", proposer.key().as_ref()],
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"member", claimant.key().as_ref()],
         bump = member.bump,
         constraint = member.is_active @ ErrorCode::MemberNotActive
     )]
     pub member: Account<'info, Member>,
-    
+
+    #[account(
+        mut,
+        seeds = [b"ledger", claimant.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ReputationLedger>,
+
+    /// CHECK: Claimant wallet receiving the reward, checked against the bounty
+    #[account(
+        mut,
+        constraint = bounty.claimant == Some(claimant.key()) @ ErrorCode::Unauthorized
+    )]
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Record of the reward payout, required when the bounty pays SOL
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TreasuryTransfer::INIT_SPACE,
+        seeds = [b"treasury_transfer", treasury.transfer_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub transfer_record: Option<Account<'info, TreasuryTransfer>>,
+
     #[account(mut)]
-    pub proposer: Signer<'info>,
-    
+    pub authority: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Vote<'info> {
+pub struct CreateDistribution<'info> {
     #[account(
+        mut,
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
     #[account(
         mut,
         seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
         bump = proposal.bump
     )]
     pub proposal: Account<'info, Proposal>,
-    
+
     #[account(
-        seeds = [b"member", voter.key().as_ref()],
-        bump = member.bump,
-        constraint = member.is_active @ ErrorCode::MemberNotActive
+        init,
+        payer = executor,
+        space = 8 + Distribution::INIT_SPACE,
+        seeds = [b"distribution", state.distribution_counter.to_le_bytes().as_ref()],
+        bump
     )]
-    pub member: Account<'info, Member>,
-    
+    pub distribution: Account<'info, Distribution>,
+
     #[account(
         init,
-        payer = voter,
-        space = 8 + VoteRecord::INIT_SPACE,
-        seeds = [b"vote", proposal.id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        payer = executor,
+        space = 8 + TreasuryTransfer::INIT_SPACE,
+        seeds = [b"treasury_transfer", treasury.transfer_count.to_le_bytes().as_ref()],
         bump
     )]
-    pub vote_record: Account<'info, VoteRecord>,
-    
+    pub transfer_record: Account<'info, TreasuryTransfer>,
+
     #[account(mut)]
-    pub voter: Signer<'info>,
-    
+    pub executor: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ExecuteProposal<'info> {
+pub struct ClaimDistribution<'info> {
     #[account(
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
         mut,
-        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
-        bump = proposal.bump
+        seeds = [b"distribution", distribution.id.to_le_bytes().as_ref()],
+        bump = distribution.bump
     )]
-    pub proposal: Account<'info, Proposal>,
-    
-    pub executor: Signer<'info>,
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + DistributionClaim::INIT_SPACE,
+        seeds = [b"distribution_claim", distribution.id.to_le_bytes().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, DistributionClaim>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct BanMember<'info> {
+pub struct ReclaimDistribution<'info> {
     #[account(
-        mut,
         seeds = [b"state"],
         bump = state.bump
     )]
     pub state: Account<'info, State>,
-    
+
     #[account(
         mut,
-        seeds = [b"member", target_authority.key().as_ref()],
-        bump = target_member.bump
+        seeds = [b"distribution", distribution.id.to_le_bytes().as_ref()],
+        bump = distribution.bump
     )]
-    pub target_member: Account<'info, Member>,
-    
-    /// CHECK: This is the authority of the member to ban
-    pub target_authority: UncheckedAccount<'info>,
-    
+    pub distribution: Account<'info, Distribution>,
+
     #[account(
-        seeds = [b"member", executor.key().as_ref()],
-        bump = executor_member.bump,
-        constraint = executor_member.is_active @ ErrorCode::MemberNotActive
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
     )]
-    pub executor_member: Account<'info, Member>,
-    
-    pub executor: Signer<'info>,
+    pub treasury: Account<'info, Treasury>,
+
+    pub cranker: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CooptMember<'info> {
+pub struct CreateReputationLedger<'info> {
     #[account(
-        mut,
-        seeds = [b"state"],
-        bump = state.bump
+        seeds = [b"member", member_authority.key().as_ref()],
+        bump = member.bump
     )]
-    pub state: Account<'info, State>,
-    
+    pub member: Account<'info, Member>,
+
+    /// CHECK: This is the authority of the member owning the ledger
+    pub member_authority: UncheckedAccount<'info>,
+
     #[account(
         init,
-        payer = sponsor,
-        space = 8 + Member::INIT_SPACE,
-        seeds = [b"member", new_member_authority.key().as_ref()],
+        payer = payer,
+        space = 8 + ReputationLedger::INIT_SPACE,
+        seeds = [b"ledger", member_authority.key().as_ref()],
         bump
     )]
-    pub new_member: Account<'info, Member>,
-    
-    /// CHECK: This is the authority for the new member
-    pub new_member_authority: UncheckedAccount<'info>,
-    
-    #[account(
-        seeds = [b"member", sponsor.key().as_ref()],
-        bump = sponsor_member.bump,
-        constraint = sponsor_member.is_active @ ErrorCode::MemberNotActive
-    )]
-    pub sponsor_member: Account<'info, Member>,
-    
+    pub ledger: Account<'info, ReputationLedger>,
+
     #[account(mut)]
-    pub sponsor: Signer<'info>,
-    
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -1223,4 +6707,226 @@ pub enum ErrorCode {
     
     #[msg("Already voted on this proposal")]
     AlreadyVoted,
+
+    #[msg("Invalid competence round window")]
+    InvalidRoundWindow,
+
+    #[msg("Review score out of range")]
+    InvalidReviewScore,
+
+    #[msg("Competence round is closed")]
+    RoundClosed,
+
+    #[msg("Competence round is still open")]
+    RoundStillOpen,
+
+    #[msg("Competence round has reached its reviewer limit")]
+    RoundFull,
+
+    #[msg("Already reviewed in this round")]
+    AlreadyReviewed,
+
+    #[msg("Members cannot review themselves")]
+    CannotReviewSelf,
+
+    #[msg("Invalid or missing remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("Too many skill domains")]
+    TooManySkillDomains,
+
+    #[msg("Invalid skill domain")]
+    InvalidSkillDomain,
+
+    #[msg("A competence profile is required for this skill domain")]
+    CompetenceProfileRequired,
+
+    #[msg("Competence profile does not cover this skill domain yet")]
+    CompetenceProfileOutOfSync,
+
+    #[msg("Check-in window is closed")]
+    CheckInClosed,
+
+    #[msg("Check-in code has expired")]
+    CheckInCodeExpired,
+
+    #[msg("Missing or invalid organizer signature for check-in")]
+    InvalidCheckInSignature,
+
+    #[msg("Already checked in for this event")]
+    AlreadyCheckedIn,
+
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+
+    #[msg("Attendance already recorded for this registration")]
+    AttendanceAlreadyRecorded,
+
+    #[msg("Attendance has not been recorded for this registration")]
+    AttendanceNotRecorded,
+
+    #[msg("Event has not been finalized yet")]
+    EventNotFinalized,
+
+    #[msg("Ghosting sweep already complete for this event")]
+    SweepComplete,
+
+    #[msg("Already registered for this event")]
+    AlreadyRegistered,
+
+    #[msg("Re-registration limit reached for this event")]
+    TooManyReregistrations,
+
+    #[msg("Invalid event capacity")]
+    InvalidCapacity,
+
+    #[msg("Event waitlist is full")]
+    WaitlistFull,
+
+    #[msg("The first waitlisted registration must be provided for promotion")]
+    WaitlistPromotionRequired,
+
+    #[msg("Event has been cancelled")]
+    EventCancelled,
+
+    #[msg("Invalid configuration value")]
+    InvalidConfig,
+
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+
+    #[msg("Proposal action already executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal action does not match this instruction")]
+    ProposalActionMismatch,
+
+    #[msg("Event has not ended yet")]
+    EventNotEnded,
+
+    #[msg("Attendance recording window is closed")]
+    RecordingWindowClosed,
+
+    #[msg("Attendance recording window is still open")]
+    RecordingWindowOpen,
+
+    #[msg("Invalid series schedule")]
+    InvalidSeriesSchedule,
+
+    #[msg("Event series has no more occurrences")]
+    SeriesComplete,
+
+    #[msg("Event does not belong to this series")]
+    SeriesMismatch,
+
+    #[msg("Too many co-organizers for this event")]
+    TooManyCoOrganizers,
+
+    #[msg("Wallet is already an organizer of this event")]
+    AlreadyOrganizer,
+
+    #[msg("Wallet is not a co-organizer of this event")]
+    NotCoOrganizer,
+
+    #[msg("Organizers cannot record their own attendance")]
+    OrganizerSelfAttendance,
+
+    #[msg("Witness was not recorded present at this event")]
+    WitnessNotPresent,
+
+    #[msg("Cannot attest your own attendance")]
+    CannotAttestSelf,
+
+    #[msg("Attested member was not recorded absent")]
+    AttestationNotDisproved,
+
+    #[msg("Witness already penalized for this attestation")]
+    AttestationAlreadySlashed,
+
+    #[msg("Attendance dispute window is closed")]
+    DisputeWindowClosed,
+
+    #[msg("Member was not recorded as ghosting")]
+    NotGhosted,
+
+    #[msg("Attendance already disputed")]
+    AlreadyDisputed,
+
+    #[msg("Excused absence already declared for this event")]
+    AbsenceAlreadyDeclared,
+
+    #[msg("Excused absence quota exceeded for the current period")]
+    ExcusedAbsenceQuotaExceeded,
+
+    #[msg("No pending excused absence to approve")]
+    NoPendingExcuse,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Treasury spend above the threshold requires a Critical proposal")]
+    CriticalProposalRequired,
+
+    #[msg("Insufficient treasury funds")]
+    InsufficientTreasuryFunds,
+
+    #[msg("Event vault account required for events taking a deposit")]
+    EventVaultRequired,
+
+    #[msg("Treasury account required to forfeit a deposit")]
+    TreasuryRequired,
+
+    #[msg("Deposit must be set before the first registration")]
+    RegistrationsAlreadyOpen,
+
+    #[msg("No deposit escrowed for this registration")]
+    NoDepositEscrowed,
+
+    #[msg("Event requires a deposit, register directly")]
+    DepositRequired,
+
+    #[msg("Attendance dispute window is still open")]
+    DisputeWindowOpen,
+
+    #[msg("Bounty is not open")]
+    BountyNotOpen,
+
+    #[msg("Bounty is not claimed")]
+    BountyNotClaimed,
+
+    #[msg("No deliverable submitted for this bounty")]
+    BountyNotSubmitted,
+
+    #[msg("Claim deadline must be in the future")]
+    InvalidClaimDeadline,
+
+    #[msg("Distribution claim deadline has passed")]
+    ClaimDeadlinePassed,
+
+    #[msg("Distribution claim period is still open")]
+    ClaimPeriodOpen,
+
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+
+    #[msg("Claims exceed the distributed amount")]
+    InsufficientDistributionFunds,
+
+    #[msg("Distribution already reclaimed")]
+    DistributionAlreadyReclaimed,
+
+    #[msg("Subscription was made inside the occurrence's late window")]
+    SubscriptionTooLate,
+
+    #[msg("Ghosting dispute has not failed yet")]
+    DisputePending,
+
+    #[msg("A bounty's reviewer cannot claim it")]
+    ReviewerCannotClaim,
+
+    #[msg("Treasury transfer record account is required")]
+    TransferRecordRequired,
+
+    #[msg("Distribution ID does not match the next distribution")]
+    DistributionIdMismatch,
 }
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::Pubkey;
+use dao::{
+    AttendanceStatus, Bounty, BountyStatus, DaoConfig, EventCategory, EventRegistration, ExcuseStatus, Member, Proposal,
+    ProposalAction, ProposalStatus, ProposalType, ReputationLedger, State, TrackSession,
+    MAX_EXCUSED_ABSENCES,
+};
+
+// Account fixtures shared by the unit tests
+
+pub fn sample_event() -> TrackSession {
+    TrackSession {
+        id: 0,
+        creator: Pubkey::new_unique(),
+        start_time: 1_000_000,
+        end_time: 1_000_000 + 2 * 3600,
+        description: String::new(),
+        is_finalized: false,
+        finalized_at: 0,
+        registered_count: 0,
+        attended_count: 0,
+        registration_seq: 0,
+        sweep_cursor: 0,
+        max_attendees: None,
+        waitlist: Vec::new(),
+        is_cancelled: false,
+        penalty_free_until: 0,
+        series_id: None,
+        co_organizers: Vec::new(),
+        category: EventCategory::Meeting,
+        schedule: DaoConfig::default().schedule(EventCategory::Meeting),
+        streak_tiers: DaoConfig::default().streak_tiers,
+        deposit_amount: 0,
+        bump: 255,
+    }
+}
+
+pub fn registration(member: &Member) -> EventRegistration {
+    EventRegistration {
+        member: member.authority,
+        event_id: 0,
+        is_registered: true,
+        is_waitlisted: false,
+        has_attended: false,
+        attendance_status: AttendanceStatus::Unrecorded,
+        attendance_delta: 0,
+        registered_at: 0,
+        index: 0,
+        reregister_count: 0,
+        attestation_count: 0,
+        is_disputed: false,
+        dispute_proposal_id: None,
+        absence_confirmed: false,
+        excuse_status: ExcuseStatus::NotRequested,
+        broken_streak: 0,
+        deposit: 0,
+        bump: 255,
+    }
+}
+
+pub fn member(presence_score: u64, competence_score: u64) -> Member {
+    Member {
+        authority: Pubkey::new_unique(),
+        presence_score,
+        competence_score,
+        is_active: true,
+        is_genesis: false,
+        joined_at: 0,
+        excused_absences: [0; MAX_EXCUSED_ABSENCES],
+        current_streak: 0,
+        longest_streak: 0,
+        bump: 255,
+    }
+}
+
+pub fn state(total_presence: u64, total_competence: u64) -> State {
+    State {
+        authority: Pubkey::new_unique(),
+        total_presence,
+        total_competence,
+        active_members: 3,
+        genesis_count: 3,
+        event_counter: 0,
+        proposal_counter: 0,
+        round_counter: 0,
+        series_counter: 0,
+        bounty_counter: 0,
+        distribution_counter: 0,
+        config: DaoConfig::default(),
+        bump: 255,
+    }
+}
+
+pub fn empty_ledger() -> ReputationLedger {
+    ReputationLedger {
+        member: Pubkey::new_unique(),
+        entries: Vec::new(),
+        head: 0,
+        total_entries: 0,
+        bump: 255,
+    }
+}
+
+pub fn proposal(id: u64, action: Option<ProposalAction>, status: ProposalStatus) -> Proposal {
+    Proposal {
+        id,
+        proposer: Pubkey::new_unique(),
+        title: String::new(),
+        description: String::new(),
+        proposal_type: ProposalType::Operational,
+        domain: None,
+        action,
+        executed: false,
+        votes_for: 0,
+        votes_against: 0,
+        total_power_snapshot: 0,
+        created_at: 0,
+        voting_ends_at: 0,
+        status,
+        bump: 255,
+    }
+}
+
+pub fn bounty(reward: u64, reviewer: Option<Pubkey>) -> Bounty {
+    Bounty {
+        id: 0,
+        description: String::new(),
+        reward,
+        competence_reward: 0,
+        reviewer,
+        status: BountyStatus::Open,
+        claimant: None,
+        deliverable_uri: String::new(),
+        deliverable_hash: [0; 32],
+        created_at: 0,
+        completed_at: 0,
+        bump: 255,
+    }
+}
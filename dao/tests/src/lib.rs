@@ -1,11 +1,33 @@
 #[cfg(test)]
+mod fixtures;
+
+#[cfg(test)]
+#[allow(deprecated)]
 mod test_initialize;
 
 #[cfg(test)]
+#[allow(deprecated, clippy::expect_fun_call, clippy::useless_vec)]
 mod test_genesis_members;
 
 #[cfg(test)]
+#[allow(clippy::identity_op)]
 mod test_events;
 
 #[cfg(test)]
+#[allow(clippy::nonminimal_bool, clippy::manual_abs_diff)]
 mod test_proposals;
+
+#[cfg(test)]
+mod test_competence;
+
+#[cfg(test)]
+mod test_reputation;
+
+#[cfg(test)]
+mod test_treasury;
+
+#[cfg(test)]
+mod test_distributions;
+
+#[cfg(test)]
+mod test_treasury_tokens;
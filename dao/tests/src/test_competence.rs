@@ -0,0 +1,145 @@
+use crate::fixtures::{member, state};
+use anchor_lang::prelude::Pubkey;
+use dao::{
+    adjust_domain_competence, calculate_voting_weight, is_outlier, weighted_median,
+    weighted_trimmed_mean, CompetenceProfile, ReviewerScore, SkillDomains, GENESIS_COMPETENCE,
+    GENESIS_PRESENCE, MAX_SKILL_DOMAINS, OUTLIER_PENALTY, OUTLIER_THRESHOLD, SCALING_FACTOR,
+};
+
+// Competence round rules:
+// - Several reviewers score one target within a window
+// - At close, the applied delta is the competence-weighted median or trimmed mean
+// - Reviewers further than OUTLIER_THRESHOLD from the consensus can lose OUTLIER_PENALTY
+// - Domain rounds and domain-tagged proposals use per-domain scores only
+
+fn review(score_delta: i64, weight: u64) -> ReviewerScore {
+    ReviewerScore {
+        reviewer: Pubkey::new_unique(),
+        score_delta,
+        weight,
+        is_outlier: false,
+    }
+}
+
+#[test]
+fn test_weighted_median_equal_weights() {
+    // Three equal genesis reviewers: the median is the middle score
+    let reviews = vec![
+        review(5, GENESIS_COMPETENCE),
+        review(1, GENESIS_COMPETENCE),
+        review(2, GENESIS_COMPETENCE),
+    ];
+
+    let consensus = weighted_median(&reviews);
+    assert_eq!(consensus, 2 * SCALING_FACTOR as i64);
+    println!("Median of [1, 2, 5] = {}", consensus / SCALING_FACTOR as i64);
+}
+
+#[test]
+fn test_weighted_median_heavy_reviewer() {
+    // A reviewer holding more than half of the weight decides the median
+    let reviews = vec![
+        review(-2, SCALING_FACTOR),
+        review(1, SCALING_FACTOR),
+        review(4, 5 * SCALING_FACTOR),
+    ];
+
+    let consensus = weighted_median(&reviews);
+    assert_eq!(consensus, 4 * SCALING_FACTOR as i64);
+    println!("Weighted median with heavy reviewer = {}", consensus / SCALING_FACTOR as i64);
+}
+
+#[test]
+fn test_weighted_trimmed_mean() {
+    // With 5 reviewers, one score is dropped on each side
+    let reviews = vec![
+        review(-10, GENESIS_COMPETENCE),
+        review(2, GENESIS_COMPETENCE),
+        review(3, GENESIS_COMPETENCE),
+        review(4, GENESIS_COMPETENCE),
+        review(10, GENESIS_COMPETENCE),
+    ];
+
+    let consensus = weighted_trimmed_mean(&reviews);
+    assert_eq!(consensus, 3 * SCALING_FACTOR as i64);
+    println!("Trimmed mean of [-10, 2, 3, 4, 10] = {}", consensus / SCALING_FACTOR as i64);
+}
+
+#[test]
+fn test_weighted_trimmed_mean_keeps_precision() {
+    // Mean of [1, 2] after trimming [0, 1, 2, 9] keeps the fractional part
+    let reviews = vec![
+        review(0, SCALING_FACTOR),
+        review(1, SCALING_FACTOR),
+        review(2, SCALING_FACTOR),
+        review(9, SCALING_FACTOR),
+    ];
+
+    let consensus = weighted_trimmed_mean(&reviews);
+    assert_eq!(consensus, 3 * SCALING_FACTOR as i64 / 2);
+}
+
+#[test]
+fn test_outlier_detection() {
+    // Consensus of 2: a reviewer at 9 is an outlier, a reviewer at 4 is not
+    let reviews = vec![
+        review(1, GENESIS_COMPETENCE),
+        review(2, GENESIS_COMPETENCE),
+        review(4, GENESIS_COMPETENCE),
+        review(9, GENESIS_COMPETENCE),
+        review(2, GENESIS_COMPETENCE),
+    ];
+    let consensus = weighted_median(&reviews);
+    assert_eq!(consensus, 2 * SCALING_FACTOR as i64);
+
+    let outliers: Vec<i64> = reviews.iter()
+        .filter(|r| is_outlier(r.score_delta, consensus))
+        .map(|r| r.score_delta)
+        .collect();
+    assert_eq!(outliers, vec![9]);
+
+    // Exactly OUTLIER_THRESHOLD away on either side is still within consensus
+    assert!(!is_outlier(2 + OUTLIER_THRESHOLD, consensus));
+    assert!(!is_outlier(2 - OUTLIER_THRESHOLD, consensus));
+    assert!(is_outlier(2 - OUTLIER_THRESHOLD - 1, consensus));
+
+    // Outlier penalty is small compared to a genesis competence score
+    const { assert!(OUTLIER_PENALTY < GENESIS_COMPETENCE) };
+    println!("Outlier penalty: {} (genesis competence: {})", OUTLIER_PENALTY, GENESIS_COMPETENCE);
+}
+
+#[test]
+fn test_domain_voting_weight_isolated() {
+    // Domain-tagged proposals use (Pres_m/Pres_tot) × (Dom_m/Dom_tot)
+    // A review in one domain must not change the weight in another
+    let member = member(GENESIS_PRESENCE, GENESIS_COMPETENCE);
+    let dao = state(3 * GENESIS_PRESENCE, 3 * GENESIS_COMPETENCE);
+    let mut domains = SkillDomains {
+        names: vec!["smart-contracts".to_string(), "design".to_string()],
+        totals: [0; MAX_SKILL_DOMAINS],
+        bump: 255,
+    };
+    domains.totals[..2].fill(3 * GENESIS_COMPETENCE);
+    let mut profile = CompetenceProfile {
+        member: member.authority,
+        scores: [GENESIS_COMPETENCE; MAX_SKILL_DOMAINS],
+        domain_count: 2,
+        bump: 255,
+    };
+
+    // Smart contracts domain: member was reviewed +5
+    let applied = adjust_domain_competence(&mut profile, &mut domains, 0, 5 * SCALING_FACTOR as i64).unwrap();
+    assert_eq!(applied, 5 * SCALING_FACTOR as i64);
+
+    let sc_weight = calculate_voting_weight(&member, &dao, Some((profile.scores[0], domains.totals[0])));
+    let design_weight = calculate_voting_weight(&member, &dao, Some((profile.scores[1], domains.totals[1])));
+    let aggregate_weight = calculate_voting_weight(&member, &dao, None);
+
+    assert!(sc_weight > design_weight, "Review should raise weight in its own domain only");
+    assert_eq!(design_weight, SCALING_FACTOR as u128 / 9, "Untouched domain keeps the equal-member weight");
+    assert_eq!(aggregate_weight, design_weight, "Domain reviews leave the aggregate competence alone");
+
+    // A domain the profile was never seeded with cannot be adjusted
+    assert!(adjust_domain_competence(&mut profile, &mut domains, 2, SCALING_FACTOR as i64).is_err());
+    println!("Smart contracts weight: {}, design weight: {}", sc_weight, design_weight);
+}
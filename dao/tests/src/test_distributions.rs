@@ -0,0 +1,87 @@
+use crate::fixtures::{member, state};
+use anchor_lang::prelude::Pubkey;
+use dao::{
+    distribution_leaf, distribution_weight, merkle_parent, verify_merkle_proof, Distribution,
+    DistributionFormula, Treasury, GENESIS_COMPETENCE, GENESIS_PRESENCE,
+};
+
+// Reward distribution rules:
+// - Shares are computed off-chain from a snapshot of each member's weight
+// - The (distribution ID, claimant, amount) leaves are committed as a Merkle root
+// - The funding proposal names the distribution ID its leaves were built for
+// - Members claim once with a proof; unclaimed funds return to the treasury after the deadline
+
+#[test]
+fn test_distribution_weights() {
+    let alice = member(GENESIS_PRESENCE, GENESIS_COMPETENCE);
+    let bob = member(GENESIS_PRESENCE, 3 * GENESIS_COMPETENCE);
+    let dao = state(2 * GENESIS_PRESENCE, 4 * GENESIS_COMPETENCE);
+
+    // Same presence, three times the competence: three times the voting weight
+    let alice_weight = distribution_weight(DistributionFormula::VotingWeight, &alice, &dao);
+    let bob_weight = distribution_weight(DistributionFormula::VotingWeight, &bob, &dao);
+    assert_eq!(bob_weight, 3 * alice_weight);
+
+    // Presence-only formula ignores competence
+    assert_eq!(
+        distribution_weight(DistributionFormula::Presence, &alice, &dao),
+        distribution_weight(DistributionFormula::Presence, &bob, &dao)
+    );
+    assert_eq!(distribution_weight(DistributionFormula::Competence, &bob, &dao), 3 * GENESIS_COMPETENCE as u128);
+}
+
+#[test]
+fn test_merkle_claims() {
+    let claimants: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+    let amounts = [400_000_000, 300_000_000, 200_000_000, 100_000_000];
+    let leaves: Vec<[u8; 32]> = claimants.iter().zip(amounts)
+        .map(|(claimant, amount)| distribution_leaf(0, claimant, amount))
+        .collect();
+
+    let left = merkle_parent(&leaves[0], &leaves[1]);
+    let right = merkle_parent(&leaves[2], &leaves[3]);
+    let root = merkle_parent(&left, &right);
+
+    // Every member proves their own share
+    assert!(verify_merkle_proof(&[leaves[1], right], &root, leaves[0]));
+    assert!(verify_merkle_proof(&[leaves[0], right], &root, leaves[1]));
+    assert!(verify_merkle_proof(&[leaves[3], left], &root, leaves[2]));
+    assert!(verify_merkle_proof(&[leaves[2], left], &root, leaves[3]));
+
+    // Claiming more, for someone else, or from another distribution fails
+    let inflated = distribution_leaf(0, &claimants[0], amounts[0] + 1);
+    assert!(!verify_merkle_proof(&[leaves[1], right], &root, inflated));
+    let stolen = distribution_leaf(0, &Pubkey::new_unique(), amounts[0]);
+    assert!(!verify_merkle_proof(&[leaves[1], right], &root, stolen));
+    let replayed = distribution_leaf(1, &claimants[0], amounts[0]);
+    assert!(!verify_merkle_proof(&[leaves[1], right], &root, replayed));
+}
+
+#[test]
+fn test_reclaim_restores_treasury_totals() {
+    // Funding counted 10 SOL as withdrawn; 6 were claimed, 4 come back
+    let mut treasury = Treasury { total_deposited: 50_000_000_000, total_withdrawn: 10_000_000_000, transfer_count: 1, bump: 255 };
+    let mut distribution = Distribution {
+        id: 0,
+        proposal_id: 0,
+        merkle_root: [0; 32],
+        formula: DistributionFormula::VotingWeight,
+        total_amount: 10_000_000_000,
+        claimed_amount: 6_000_000_000,
+        claim_count: 3,
+        claim_deadline: 1_000,
+        created_at: 0,
+        is_reclaimed: false,
+        bump: 255,
+    };
+
+    assert!(distribution.reclaim(&mut treasury, 1_000).is_err(), "Claims are still open");
+    assert_eq!(distribution.reclaim(&mut treasury, 1_001).unwrap(), 4_000_000_000);
+    assert!(distribution.is_reclaimed);
+
+    // Only what members received stays counted as withdrawn; deposits are untouched
+    assert_eq!(treasury.total_withdrawn, 6_000_000_000);
+    assert_eq!(treasury.total_deposited, 50_000_000_000);
+    assert!(distribution.reclaim(&mut treasury, 2_000).is_err(), "Reclaimed only once");
+    assert_eq!(treasury.total_withdrawn, 6_000_000_000);
+}
@@ -1,4 +1,6 @@
-use dao::{ATTENDANCE_REWARD, GENESIS_PRESENCE, GHOSTING_PENALTY, LATE_PENALTY, OUBLI_PENALTY, SCALING_FACTOR, SLOT_DURATION};
+use crate::fixtures::{empty_ledger, member, proposal, registration, sample_event};
+use anchor_lang::prelude::Pubkey;
+use dao::{apply_attendance, check_in_code_valid, late_penalty_amount, revert_attendance, AttendanceStatus, DaoConfig, ExcuseStatus, ProposalStatus, LatePenaltyCurve, EventCategory, EventSeries, Recurrence, SeriesEnd, SeriesSubscription, ATTENDANCE_REWARD, CHECK_IN_CODE_TTL, CHECK_IN_WINDOW, FALSE_ATTESTATION_PENALTY, GENESIS_PRESENCE, GHOSTING_PENALTY, LATE_PENALTY, MAX_ATTENDANCE_BATCH, MAX_MULTIPLIER_BPS, MAX_REREGISTRATIONS, OUBLI_PENALTY, ReputationReason, SCALING_FACTOR, SLOT_DURATION};
 
 /// Test constants documentation
 /// 
@@ -105,3 +107,606 @@ fn test_slashing_protection() {
         penalty / SCALING_FACTOR,
         after_slash);
 }
+
+#[test]
+fn test_self_check_in_window() {
+    // Self check-in opens CHECK_IN_WINDOW before the event start and closes
+    // when the event ends, with organizer-signed codes that live at most
+    // CHECK_IN_CODE_TTL
+    let event = sample_event();
+    let opens_at = event.start_time - CHECK_IN_WINDOW;
+
+    assert!(!event.check_in_open(opens_at - 1).unwrap());
+    assert!(event.check_in_open(opens_at).unwrap());
+    assert!(event.check_in_open(event.start_time).unwrap());
+    assert!(event.check_in_open(event.end_time).unwrap());
+    assert!(!event.check_in_open(event.end_time + 1).unwrap());
+
+    // A code expiring further than the TTL away is rejected, as is an expired one
+    let now = event.start_time;
+    assert!(check_in_code_valid(now, now + CHECK_IN_CODE_TTL));
+    assert!(!check_in_code_valid(now, now + CHECK_IN_CODE_TTL + 1));
+    assert!(!check_in_code_valid(now, now - 1));
+
+    println!("Check-in open from {} to {} (code TTL {}s)", opens_at, event.end_time, CHECK_IN_CODE_TTL);
+}
+
+#[test]
+fn test_attendance_batch_fits_transaction() {
+    // Each batch entry passes (Member, EventRegistration, ReputationLedger)
+    // Fixed accounts: state, event, organizer member, organizer, program, compute budget
+    const MAX_ACCOUNT_LOCKS: usize = 64;
+    let fixed_accounts = 6;
+    let batch_accounts = MAX_ATTENDANCE_BATCH * 3;
+
+    assert!(batch_accounts + fixed_accounts <= MAX_ACCOUNT_LOCKS, "Batch must fit the account lock limit");
+
+    // Net presence change of a batch: 3 present, 1 ghost, accumulated
+    // into one running total as the instruction does
+    let mut event = sample_event();
+    let mut total_presence = 4 * GENESIS_PRESENCE;
+    for was_present in [true, true, false, true] {
+        let mut member = member(GENESIS_PRESENCE, 0);
+        let mut registration = registration(&member);
+        let mut ledger = empty_ledger();
+        apply_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, was_present, 0).unwrap();
+        assert_eq!(ledger.entries.len(), 1);
+    }
+
+    assert_eq!(total_presence, 4 * GENESIS_PRESENCE + 3 * ATTENDANCE_REWARD - GHOSTING_PENALTY);
+    assert_eq!(total_presence, 13 * SCALING_FACTOR);
+    assert_eq!(event.attended_count, 3);
+    println!("Batch of {} members uses {} accounts", MAX_ATTENDANCE_BATCH, batch_accounts + fixed_accounts);
+}
+
+#[test]
+fn test_attendance_correction_reverses_applied_delta() {
+    // A ghosting penalty capped at a low balance must be reversed by the
+    // amount actually applied, not by the nominal penalty
+    let mut event = sample_event();
+    let mut member = member(SCALING_FACTOR, 0);
+    let mut registration = registration(&member);
+    let mut ledger = empty_ledger();
+    let mut total_presence = 3 * SCALING_FACTOR;
+
+    apply_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, false, 0).unwrap();
+    assert_eq!(member.presence_score, 0);
+    assert_eq!(registration.attendance_delta, -(SCALING_FACTOR as i64), "Only the available balance is slashed");
+
+    // Recording twice is rejected: corrections go through the reversal
+    assert!(apply_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, true, 0).is_err());
+
+    // Correction to "present": reverse the applied delta, then reward
+    revert_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, 0).unwrap();
+    assert_eq!(member.presence_score, SCALING_FACTOR, "Reversal restores the original balance");
+    assert_eq!(total_presence, 3 * SCALING_FACTOR);
+    assert!(registration.attendance_status == AttendanceStatus::Unrecorded);
+
+    apply_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, true, 0).unwrap();
+    assert_eq!(member.presence_score, SCALING_FACTOR + ATTENDANCE_REWARD);
+    assert_eq!(event.attended_count, 1);
+    assert!(ledger.entries[1].reason == ReputationReason::AttendanceCorrection);
+
+    println!("Ghosted 1 -> 0, corrected to present -> {}", member.presence_score / SCALING_FACTOR);
+}
+
+#[test]
+fn test_oubli_recording() {
+    // A member present without registering gets an unregistered record
+    // created on the fly and the oubli penalty
+    let mut event = sample_event();
+    let mut present = member(GENESIS_PRESENCE, 0);
+    let mut absent = member(GENESIS_PRESENCE, 0);
+    let mut present_registration = registration(&present);
+    let mut absent_registration = registration(&absent);
+    present_registration.is_registered = false;
+    absent_registration.is_registered = false;
+    let mut ledger = empty_ledger();
+    let mut total_presence = 3 * GENESIS_PRESENCE;
+
+    apply_attendance(&mut total_presence, &mut event, &mut present_registration, &mut present, &mut ledger, true, 0).unwrap();
+    assert!(present_registration.attendance_status == AttendanceStatus::Oubli);
+    assert!(present_registration.has_attended);
+    assert_eq!(present.presence_score, GENESIS_PRESENCE - OUBLI_PENALTY);
+    assert_eq!(total_presence, 3 * GENESIS_PRESENCE - OUBLI_PENALTY);
+    assert_eq!(event.attended_count, 1, "Oubli members count as attendees");
+    assert!(ledger.entries[0].reason == ReputationReason::Oubli);
+    assert_eq!(present.current_streak, 0, "Oubli does not extend the streak");
+
+    // Absent and unregistered: nothing is recorded
+    apply_attendance(&mut total_presence, &mut event, &mut absent_registration, &mut absent, &mut ledger, false, 0).unwrap();
+    assert_eq!(absent.presence_score, GENESIS_PRESENCE);
+    assert_eq!(ledger.entries.len(), 1);
+}
+
+#[test]
+fn test_ghost_sweep_cursor() {
+    // Three registrations: one recorded present, one withdrawn, one never recorded
+    let mut event = sample_event();
+    event.is_finalized = true;
+    event.registration_seq = 3;
+    let mut members: Vec<_> = (0..3).map(|_| member(GENESIS_PRESENCE, 0)).collect();
+    let mut registrations: Vec<_> = members.iter().map(registration).collect();
+    for (index, registration) in registrations.iter_mut().enumerate() {
+        registration.index = index as u32;
+    }
+    registrations[0].attendance_status = AttendanceStatus::Present;
+    registrations[1].is_registered = false;
+    let mut total_presence = 3 * GENESIS_PRESENCE;
+
+    // Registrations must be passed in cursor order
+    assert!(event.advance_sweep(1).is_err());
+
+    // First transaction sweeps the first two: nothing to penalize yet
+    for registration in &registrations[..2] {
+        assert!(!event.advance_sweep(registration.index).unwrap());
+        assert!(!registration.awaits_ghosting());
+    }
+    assert_eq!(event.sweep_cursor, 2, "Cursor moves past non-ghost registrations");
+
+    // Second transaction finishes the sweep and ghosts the last one
+    let registration = &mut registrations[2];
+    assert!(event.advance_sweep(registration.index).unwrap());
+    assert!(registration.awaits_ghosting());
+    apply_attendance(&mut total_presence, &mut event, registration, &mut members[2], &mut empty_ledger(), false, 0).unwrap();
+    assert!(registration.attendance_status == AttendanceStatus::Ghosted);
+    assert!(!registration.awaits_ghosting());
+    assert_eq!(total_presence, 3 * GENESIS_PRESENCE - GHOSTING_PENALTY);
+    assert_eq!(event.sweep_cursor, event.registration_seq);
+}
+
+#[test]
+fn test_reregistration_cap() {
+    let member = member(GENESIS_PRESENCE, 0);
+    let mut registration = registration(&member);
+
+    // Still registered: nothing to re-register
+    assert!(registration.record_reregistration(100).is_err());
+
+    // Withdraw and come back up to MAX_REREGISTRATIONS times
+    for attempt in 1..=MAX_REREGISTRATIONS {
+        registration.record_withdrawal();
+        registration.record_reregistration(100 + attempt as i64).unwrap();
+        registration.is_registered = true;
+        assert_eq!(registration.reregister_count, attempt);
+        assert_eq!(registration.registered_at, 100 + attempt as i64);
+    }
+
+    // One more withdrawal is final
+    registration.is_registered = false;
+    assert!(registration.record_reregistration(200).is_err());
+    assert_eq!(registration.reregister_count, MAX_REREGISTRATIONS);
+}
+
+#[test]
+fn test_event_capacity_and_waitlist() {
+    let mut event = sample_event();
+    event.max_attendees = Some(2);
+
+    event.registered_count = 1;
+    assert!(!event.is_full(), "One seat left");
+    event.registered_count = 2;
+    assert!(event.is_full(), "Third registration goes to the waitlist");
+
+    // Withdrawal frees a seat, promotion takes it again (FIFO)
+    let mut first = registration(&member(GENESIS_PRESENCE, 0));
+    let mut second = registration(&member(GENESIS_PRESENCE, 0));
+    for waiting in [&mut first, &mut second] {
+        waiting.is_registered = false;
+        waiting.is_waitlisted = true;
+        event.waitlist.push(waiting.member);
+    }
+    event.registered_count -= 1;
+    assert!(!event.is_full());
+
+    // Only the head of the waitlist can take the seat
+    assert!(event.promote_from_waitlist(&mut second, 500).is_err());
+    assert!(second.is_waitlisted && event.registered_count == 1);
+    event.promote_from_waitlist(&mut first, 500).unwrap();
+    assert!(first.is_registered && !first.is_waitlisted);
+    assert_eq!(first.registered_at, 500);
+    assert_eq!(event.waitlist, vec![second.member]);
+    assert_eq!(event.registered_count, 2);
+    assert!(event.is_full());
+
+    // A registration promoted once is not promoted again
+    assert!(event.promote_from_waitlist(&mut first, 600).is_err());
+    event.registered_count -= 1;
+    event.promote_from_waitlist(&mut second, 600).unwrap();
+    assert!(event.waitlist.is_empty());
+    assert!(event.is_full());
+
+    // Unlimited events never fill up
+    event.max_attendees = None;
+    assert!(!event.is_full());
+}
+
+#[test]
+fn test_reschedule_grace_period() {
+    // Rescheduling opens a penalty-free withdrawal window from the change
+    let config = DaoConfig::default();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.reschedule_grace_period, 2 * SLOT_DURATION);
+
+    let mut event = sample_event();
+    let duration = event.end_time - event.start_time;
+    let rescheduled_at = event.start_time - 3 * SLOT_DURATION;
+    let new_start_time = rescheduled_at + SLOT_DURATION / 2;
+    event.reschedule(new_start_time, rescheduled_at, config.reschedule_grace_period).unwrap();
+    assert_eq!(event.start_time, new_start_time);
+    assert_eq!(event.end_time - event.start_time, duration, "Duration is kept");
+
+    // Withdrawing right before the new start would normally be a late withdrawal
+    let withdraw_at = new_start_time - 60;
+    assert!(late_penalty_amount(&config, &event.schedule, new_start_time - withdraw_at).unwrap() > 0);
+    assert!(!event.withdrawal_penalized(withdraw_at), "Withdrawal falls inside the grace period");
+
+    // Once the grace period is over the usual rules apply again
+    assert!(event.withdrawal_penalized(rescheduled_at + config.reschedule_grace_period));
+    event.is_cancelled = true;
+    assert!(!event.withdrawal_penalized(rescheduled_at + config.reschedule_grace_period));
+
+    let mut invalid = config;
+    invalid.reschedule_grace_period = -1;
+    assert!(invalid.validate().is_err());
+    println!("Penalty-free withdrawal for {}h after a reschedule", config.reschedule_grace_period / 3600);
+}
+
+#[test]
+fn test_attendance_recording_window() {
+    // Attendance is recorded between start and end + grace period,
+    // after which anyone can close the event out
+    let config = DaoConfig::default();
+    let mut event = sample_event();
+
+    let closes_at = event.recording_closes_at(config.attendance_grace_period).unwrap();
+    assert_eq!(closes_at, event.end_time + SLOT_DURATION);
+    assert!(event.start_time < event.end_time && event.end_time < closes_at);
+
+    // The event can be closed out only once the window is over
+    let grace = config.attendance_grace_period;
+    assert!(event.check_closable(event.end_time, grace).is_err());
+    assert!(event.check_closable(closes_at, grace).is_err(), "Last recording second");
+    event.check_closable(closes_at + 1, grace).unwrap();
+
+    // Closed out once, and never when cancelled
+    event.is_finalized = true;
+    assert!(event.check_closable(closes_at + 1, grace).is_err());
+    let mut cancelled = sample_event();
+    cancelled.is_cancelled = true;
+    assert!(cancelled.check_closable(closes_at + 1, grace).is_err());
+
+    event.end_time = i64::MAX;
+    assert!(event.recording_closes_at(config.attendance_grace_period).is_err());
+    println!("Recording closes {}h after the event ends", config.attendance_grace_period / 3600);
+}
+
+#[test]
+fn test_series_occurrences() {
+    let week = 7 * SLOT_DURATION;
+    let mut series = EventSeries {
+        id: 0,
+        creator: Pubkey::new_unique(),
+        description: String::new(),
+        first_start_time: 1_000_000,
+        duration: 3600,
+        recurrence: Recurrence::Weekly,
+        end: SeriesEnd::Count(4),
+        max_attendees: None,
+        category: EventCategory::Meeting,
+        next_occurrence: 0,
+        spawned_count: 0,
+        bump: 255,
+    };
+
+    assert_eq!(series.occurrence_start(2).unwrap(), 1_000_000 + 2 * week);
+
+    // Before the first occurrence, the crank spawns occurrences in order
+    assert_eq!(series.next_upcoming_occurrence(0).unwrap(), 0);
+
+    // A late crank skips occurrences that already started
+    let during_second_week = 1_000_000 + week + 60;
+    assert_eq!(series.next_upcoming_occurrence(during_second_week).unwrap(), 2);
+
+    // A count-limited series stops after its last occurrence
+    assert!(!series.is_past_end(3, series.occurrence_start(3).unwrap()));
+    assert!(series.is_past_end(4, series.occurrence_start(4).unwrap()));
+
+    // A date-limited biweekly series stops after the until date
+    series.recurrence = Recurrence::Biweekly;
+    series.end = SeriesEnd::Until(1_000_000 + 4 * week);
+    assert!(!series.is_past_end(2, series.occurrence_start(2).unwrap()));
+    assert!(series.is_past_end(3, series.occurrence_start(3).unwrap()));
+    println!("Biweekly interval: {} days", Recurrence::Biweekly.interval() / SLOT_DURATION);
+}
+
+#[test]
+fn test_late_subscription_not_applied() {
+    let event = sample_event();
+    let window = event.schedule.late_window;
+    let mut subscription = SeriesSubscription {
+        member: Pubkey::new_unique(),
+        series_id: 0,
+        subscribed_at: event.start_time - 7 * SLOT_DURATION,
+        bump: 255,
+    };
+
+    // Subscribed a week ahead: the occurrence is joined penalty-free
+    assert!(subscription.predates_late_window(&event).unwrap());
+    subscription.subscribed_at = event.start_time - window;
+    assert!(subscription.predates_late_window(&event).unwrap());
+
+    // Subscribing inside the late window would dodge the late registration penalty
+    subscription.subscribed_at = event.start_time - window + 1;
+    assert!(!subscription.predates_late_window(&event).unwrap());
+    subscription.subscribed_at = event.start_time + 60;
+    assert!(!subscription.predates_late_window(&event).unwrap());
+}
+
+#[test]
+fn test_category_schedules() {
+    let config = DaoConfig::default();
+    let meeting = config.schedule(EventCategory::Meeting);
+    let workshop = config.schedule(EventCategory::Workshop);
+    let hackathon = config.schedule(EventCategory::Hackathon);
+    let mandatory = config.schedule(EventCategory::Mandatory);
+
+    // Meetings keep the base reward and penalties
+    assert_eq!(meeting.scaled_reward(ATTENDANCE_REWARD).unwrap(), ATTENDANCE_REWARD);
+    assert_eq!(meeting.scaled_penalty(GHOSTING_PENALTY).unwrap(), GHOSTING_PENALTY);
+    assert_eq!(meeting.late_window, SLOT_DURATION);
+
+    // Longer formats pay more and charge more
+    assert_eq!(workshop.scaled_reward(ATTENDANCE_REWARD).unwrap(), 3 * ATTENDANCE_REWARD / 2);
+    assert_eq!(hackathon.scaled_reward(ATTENDANCE_REWARD).unwrap(), 2 * ATTENDANCE_REWARD);
+    assert!(hackathon.late_window > meeting.late_window);
+
+    // Skipping a mandatory event doubles the ghosting penalty
+    assert_eq!(mandatory.scaled_penalty(GHOSTING_PENALTY).unwrap(), 2 * GHOSTING_PENALTY);
+
+    let mut invalid = config;
+    invalid.category_schedules[1].reward_multiplier_bps = MAX_MULTIPLIER_BPS + 1;
+    assert!(invalid.validate().is_err());
+    println!("Hackathon reward: {}", hackathon.scaled_reward(ATTENDANCE_REWARD).unwrap());
+}
+
+#[test]
+fn test_co_organizers_share_privileges() {
+    let mut event = sample_event();
+    let co_organizer = Pubkey::new_unique();
+    let attendee = Pubkey::new_unique();
+
+    let creator = event.creator;
+    assert!(event.is_organizer(&creator));
+    assert!(!event.is_organizer(&co_organizer));
+
+    event.co_organizers.push(co_organizer);
+    assert!(event.is_organizer(&co_organizer), "Co-organizers can record and finalize");
+    assert!(!event.is_organizer(&attendee));
+}
+
+#[test]
+fn test_peer_attestation_threshold() {
+    // A member is recorded present once enough distinct witnesses attested
+    let config = DaoConfig::default();
+    assert_eq!(config.attestation_threshold, 2);
+    let mut event = sample_event();
+    let mut total_presence = 3 * GENESIS_PRESENCE;
+
+    // One attestation short of the threshold: the sweep ghosts the subject,
+    // but that alone does not prove the witness lied
+    let mut subject = member(GENESIS_PRESENCE, 0);
+    let mut swept = registration(&subject);
+    swept.attestation_count = config.attestation_threshold - 1;
+    apply_attendance(&mut total_presence, &mut event, &mut swept, &mut subject, &mut empty_ledger(), false, 0).unwrap();
+    assert!(swept.attendance_status == AttendanceStatus::Ghosted);
+    assert!(!swept.is_confirmed_absent(None, 0), "Sweep ghosting never slashes witnesses");
+
+    // Disputed: witnesses are slashed only once that dispute failed
+    swept.is_disputed = true;
+    swept.dispute_proposal_id = Some(7);
+    let mut pending = proposal(7, None, ProposalStatus::Active);
+    pending.voting_ends_at = 100;
+    assert!(!swept.is_confirmed_absent(Some(&pending), 50));
+    assert!(!swept.is_confirmed_absent(Some(&proposal(8, None, ProposalStatus::Rejected)), 50));
+    assert!(swept.is_confirmed_absent(Some(&proposal(7, None, ProposalStatus::Rejected)), 50));
+
+    // An organizer recording the absence confirms it
+    let mut recorded_absent = registration(&subject);
+    recorded_absent.attestation_count = 1;
+    apply_attendance(&mut total_presence, &mut event, &mut recorded_absent, &mut subject, &mut empty_ledger(), false, 0).unwrap();
+    recorded_absent.absence_confirmed = true;
+    assert!(recorded_absent.is_confirmed_absent(None, 0));
+
+    // Each false witness loses as much as a ghost would
+    let penalty = event.schedule.scaled_penalty(FALSE_ATTESTATION_PENALTY).unwrap();
+    assert_eq!(penalty, GHOSTING_PENALTY);
+
+    let mut invalid = config;
+    invalid.attestation_threshold = 0;
+    assert!(invalid.validate().is_err(), "At least one witness is required");
+    println!("False attestation penalty: {}", penalty / SCALING_FACTOR);
+}
+
+#[test]
+fn test_attendance_dispute_reversal() {
+    // A passed dispute reverses the ghosting penalty and applies the reward,
+    // reconnecting the streak the ghosting broke
+    let config = DaoConfig::default();
+    let mut event = sample_event();
+    let mut member = member(GENESIS_PRESENCE, 0);
+    member.current_streak = 4;
+    let mut registration = registration(&member);
+    let mut ledger = empty_ledger();
+    let mut total_presence = 3 * GENESIS_PRESENCE;
+
+    apply_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, false, 0).unwrap();
+    assert_eq!(member.presence_score, GENESIS_PRESENCE - GHOSTING_PENALTY);
+    assert_eq!(member.current_streak, 0);
+
+    revert_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, 1).unwrap();
+    apply_attendance(&mut total_presence, &mut event, &mut registration, &mut member, &mut ledger, true, 1).unwrap();
+
+    let reward = ATTENDANCE_REWARD * event.streak_bonus_bps(5) as u64 / 10_000;
+    assert_eq!(member.current_streak, 5, "Streak continues as if never broken");
+    assert_eq!(member.presence_score, GENESIS_PRESENCE + reward);
+    assert_eq!(total_presence, 3 * GENESIS_PRESENCE + reward);
+    assert!(registration.attendance_status == AttendanceStatus::Present);
+    assert_eq!(ledger.entries.len(), 3, "Ghosting, correction and reward stay traceable");
+
+    // Disputes are accepted for a week after finalization
+    assert_eq!(config.dispute_window, 7 * SLOT_DURATION);
+}
+
+#[test]
+fn test_excused_absence_rolling_quota() {
+    let config = DaoConfig::default();
+    let mut member = member(GENESIS_PRESENCE, 0);
+    let period = config.excused_absence_period;
+    let now = 10 * period;
+
+    member.record_excused_absence(now - period / 2);
+    member.record_excused_absence(now - period / 4);
+    assert_eq!(member.excused_absences_in_period(now, period), 2);
+    assert!(member.excused_absences_in_period(now, period) >= config.excused_absence_quota, "Quota reached");
+
+    // Once the oldest excuse leaves the rolling period, a new one is allowed
+    let later = now + period / 2;
+    assert_eq!(member.excused_absences_in_period(later, period), 1);
+    member.record_excused_absence(later);
+    assert_eq!(member.excused_absences_in_period(later, period), 2);
+    println!("{} excused absences per {} days", config.excused_absence_quota, period / SLOT_DURATION);
+}
+
+#[test]
+fn test_excused_absence_recording() {
+    let mut event = sample_event();
+    let mut absent = member(GENESIS_PRESENCE, 0);
+    let mut total_presence = GENESIS_PRESENCE;
+    let mut ledger = empty_ledger();
+
+    // The ghost sweep records an approved absence as excused, without penalty
+    let mut excused = registration(&absent);
+    excused.excuse_status = ExcuseStatus::Approved;
+    assert!(excused.awaits_ghosting());
+    apply_attendance(&mut total_presence, &mut event, &mut excused, &mut absent, &mut ledger, false, 0).unwrap();
+    assert!(excused.attendance_status == AttendanceStatus::Excused);
+    assert_eq!(excused.attendance_delta, 0);
+    assert!(ledger.entries.is_empty());
+    assert_eq!(absent.presence_score, GENESIS_PRESENCE);
+    assert_eq!(total_presence, GENESIS_PRESENCE);
+    assert!(!excused.awaits_ghosting(), "Swept once");
+
+    // An excuse does not survive a withdrawal: coming back and missing the event is ghosting
+    let mut returning = registration(&absent);
+    returning.excuse_status = ExcuseStatus::Approved;
+    returning.record_withdrawal();
+    assert!(returning.excuse_status == ExcuseStatus::NotRequested);
+    returning.record_reregistration(100).unwrap();
+    returning.is_registered = true;
+    apply_attendance(&mut total_presence, &mut event, &mut returning, &mut absent, &mut ledger, false, 200).unwrap();
+    assert!(returning.attendance_status == AttendanceStatus::Ghosted);
+    assert!(ledger.entries[0].reason == ReputationReason::Ghosting);
+}
+
+#[test]
+fn test_graduated_late_penalty() {
+    let mut config = DaoConfig::default();
+    let schedule = config.schedule(EventCategory::Meeting);
+    let window = schedule.late_window;
+
+    // Outside the late window: free
+    assert_eq!(late_penalty_amount(&config, &schedule, window).unwrap(), 0);
+
+    // Linear (default): 23h59 before costs almost nothing, the last minute almost everything
+    assert!(late_penalty_amount(&config, &schedule, window - 60).unwrap() < LATE_PENALTY / 100);
+    assert_eq!(late_penalty_amount(&config, &schedule, window / 2).unwrap(), LATE_PENALTY / 2);
+    assert_eq!(late_penalty_amount(&config, &schedule, 0).unwrap(), LATE_PENALTY);
+
+    // Piecewise: default steps charge 25/50/75/100%
+    config.late_penalty_curve = LatePenaltyCurve::Piecewise;
+    assert_eq!(late_penalty_amount(&config, &schedule, window - 60).unwrap(), LATE_PENALTY / 4);
+    assert_eq!(late_penalty_amount(&config, &schedule, window / 10).unwrap(), LATE_PENALTY);
+
+    // Cliff keeps the original flat penalty
+    config.late_penalty_curve = LatePenaltyCurve::Cliff;
+    assert_eq!(late_penalty_amount(&config, &schedule, window - 60).unwrap(), LATE_PENALTY);
+
+    // Steps must be sorted
+    config.late_penalty_steps.swap(0, 1);
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_streak_bonus_tiers() {
+    let event = sample_event();
+    let base = event.schedule.scaled_reward(ATTENDANCE_REWARD).unwrap();
+
+    // No bonus before the first tier
+    assert_eq!(event.streak_bonus_bps(1), 10_000);
+    assert_eq!(event.streak_bonus_bps(2), 10_000);
+
+    // Highest tier reached applies
+    assert_eq!(event.streak_bonus_bps(3), 11_000);
+    assert_eq!(event.streak_bonus_bps(7), 12_500);
+    assert_eq!(event.streak_bonus_bps(42), 15_000);
+
+    let reward_at_ten = base * event.streak_bonus_bps(10) as u64 / 10_000;
+    assert_eq!(reward_at_ten, 3 * ATTENDANCE_REWARD / 2);
+
+    // Tiers must be increasing
+    let mut config = DaoConfig::default();
+    config.streak_tiers[2].min_streak = 4;
+    assert!(config.validate().is_err());
+    println!("Reward after a 10-event streak: {}", reward_at_ten);
+}
+
+#[test]
+fn test_deposit_forfeited_inside_late_window() {
+    let mut event = sample_event();
+    event.deposit_amount = 50_000_000;
+    let window = event.schedule.late_window;
+
+    // Timely withdrawal gets the deposit back
+    assert!(!event.in_late_window(event.start_time - window).unwrap());
+    assert!(!event.in_late_window(event.start_time - 2 * window).unwrap());
+
+    // Withdrawing inside the late window forfeits it to the treasury
+    assert!(event.in_late_window(event.start_time - window + 1).unwrap());
+    assert!(event.in_late_window(event.start_time - 60).unwrap());
+
+    // Hackathons have a longer late window than meetings
+    let mut hackathon = sample_event();
+    hackathon.schedule = DaoConfig::default().schedule(EventCategory::Hackathon);
+    assert!(hackathon.in_late_window(hackathon.start_time - window).unwrap());
+    println!("Deposit of {} lamports at risk in the last {}h", event.deposit_amount, window / 3600);
+}
+
+#[test]
+fn test_disputed_deposit_held_until_dispute_fails() {
+    // A ghosted deposit under dispute is only forfeited once the dispute failed
+    let mut ghost = registration(&member(GENESIS_PRESENCE, 0));
+    ghost.attendance_status = AttendanceStatus::Ghosted;
+    ghost.is_disputed = true;
+    ghost.dispute_proposal_id = Some(3);
+
+    let mut dispute = proposal(3, None, ProposalStatus::Active);
+    dispute.voting_ends_at = 1_000;
+    dispute.votes_for = 10;
+    dispute.votes_against = 4;
+    assert!(!ghost.dispute_failed(None, 2_000), "The dispute proposal is required");
+    assert!(!ghost.dispute_failed(Some(&dispute), 500), "Voting is still open");
+    assert!(!ghost.dispute_failed(Some(&dispute), 2_000), "Passing dispute awaits resolution");
+
+    // Expired short of a majority, even before anyone tallied it
+    dispute.votes_against = 10;
+    assert!(dispute.status == ProposalStatus::Active);
+    assert!(ghost.dispute_failed(Some(&dispute), 1_000));
+
+    dispute.status = ProposalStatus::Rejected;
+    assert!(ghost.dispute_failed(Some(&dispute), 500));
+    assert!(!ghost.dispute_failed(Some(&proposal(4, None, ProposalStatus::Rejected)), 2_000));
+
+    let passed = proposal(3, None, ProposalStatus::Passed);
+    assert!(!ghost.dispute_failed(Some(&passed), 2_000));
+}
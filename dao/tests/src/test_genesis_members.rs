@@ -21,6 +21,10 @@ fn get_member_pda(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"member", authority.as_ref()], program_id)
 }
 
+fn get_ledger_pda(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ledger", authority.as_ref()], program_id)
+}
+
 #[test]
 fn test_add_genesis_members() {
     let program_id_str = "Ft54i1cMxhkD5pvxMHfmzW8quwPZRPVQRTcqMFLXqYzi";
@@ -53,12 +57,14 @@ fn test_add_genesis_members() {
 
     for (i, member_keypair) in genesis_members.iter().enumerate() {
         let (member_pda, _) = get_member_pda(&program_id, &member_keypair.pubkey());
+        let (ledger_pda, _) = get_ledger_pda(&program_id, &member_keypair.pubkey());
 
         let tx = program
             .request()
             .accounts(dao::accounts::AddGenesisMember {
                 state: state_pda,
                 member: member_pda,
+                ledger: ledger_pda,
                 member_authority: member_keypair.pubkey(),
                 authority: payer.pubkey(),
                 system_program: system_program::ID,
@@ -94,12 +100,14 @@ fn test_add_genesis_members() {
     // Attempting to add a 4th genesis member should fail
     let extra_member = Keypair::new();
     let (extra_member_pda, _) = get_member_pda(&program_id, &extra_member.pubkey());
+    let (extra_ledger_pda, _) = get_ledger_pda(&program_id, &extra_member.pubkey());
 
     let result = program
         .request()
         .accounts(dao::accounts::AddGenesisMember {
             state: state_pda,
             member: extra_member_pda,
+            ledger: extra_ledger_pda,
             member_authority: extra_member.pubkey(),
             authority: payer.pubkey(),
             system_program: system_program::ID,
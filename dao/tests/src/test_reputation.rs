@@ -0,0 +1,86 @@
+use crate::fixtures::{bounty, empty_ledger, member};
+use anchor_lang::prelude::Pubkey;
+use dao::{
+    BountyStatus, DaoConfig, ProposalAction, ReputationReason, ScoreKind, GENESIS_COMPETENCE,
+    GENESIS_PRESENCE, GHOSTING_PENALTY, LATE_PENALTY, LEDGER_CAPACITY, SCALING_FACTOR,
+};
+
+// Reputation ledger rules:
+// - Every presence/competence change appends an entry (timestamp, reference, reason, delta)
+// - The ledger is a ring buffer of LEDGER_CAPACITY entries, oldest overwritten first
+
+#[test]
+fn test_ledger_records_entries() {
+    let mut ledger = empty_ledger();
+
+    ledger.record(100, Some(7), ReputationReason::LateRegistration, ScoreKind::Presence, -(LATE_PENALTY as i64));
+    ledger.record(200, Some(7), ReputationReason::Ghosting, ScoreKind::Presence, -(GHOSTING_PENALTY as i64));
+
+    assert_eq!(ledger.entries.len(), 2);
+    assert_eq!(ledger.total_entries, 2);
+    assert_eq!(ledger.head, 2);
+    assert!(ledger.entries[1].reason == ReputationReason::Ghosting);
+    assert_eq!(ledger.entries[1].reference_id, Some(7));
+    assert_eq!(ledger.entries[1].delta, -(GHOSTING_PENALTY as i64));
+    println!("Presence drop explained by {} ledger entries", ledger.entries.len());
+}
+
+#[test]
+fn test_ledger_ring_buffer_wraps() {
+    let mut ledger = empty_ledger();
+
+    // Fill the buffer and write two more entries
+    for i in 0..(LEDGER_CAPACITY as i64 + 2) {
+        ledger.record(i, Some(i as u64), ReputationReason::Attendance, ScoreKind::Presence, 1);
+    }
+
+    assert_eq!(ledger.entries.len(), LEDGER_CAPACITY, "Ledger never grows past capacity");
+    assert_eq!(ledger.total_entries, LEDGER_CAPACITY as u64 + 2);
+    assert_eq!(ledger.head, 2);
+
+    // The two oldest entries were overwritten by the newest ones
+    assert_eq!(ledger.entries[0].timestamp, LEDGER_CAPACITY as i64);
+    assert_eq!(ledger.entries[1].timestamp, LEDGER_CAPACITY as i64 + 1);
+    assert_eq!(ledger.entries[2].timestamp, 2);
+}
+
+#[test]
+fn test_bounty_competence_is_traceable() {
+    let config = DaoConfig::default();
+    let reviewer = Pubkey::new_unique();
+    let mut claimant = member(GENESIS_PRESENCE, GENESIS_COMPETENCE);
+    let mut total_competence = GENESIS_COMPETENCE;
+    let mut ledger = empty_ledger();
+    let mut task = bounty(0, Some(reviewer));
+    task.id = 3;
+    task.competence_reward = 5;
+
+    // Claimed by a member other than the reviewer, then submitted
+    assert!(task.check_claimable(&reviewer).is_err(), "The reviewer cannot claim");
+    task.check_claimable(&claimant.authority).unwrap();
+    task.claimant = Some(claimant.authority);
+    task.status = BountyStatus::Submitted;
+    task.deliverable_hash = [9; 32];
+
+    // The reviewer approves alone; a proposal would have to name this deliverable
+    assert!(!task.requires_proposal(&reviewer, &config));
+    assert!(task.approval_action().unwrap() == ProposalAction::ApproveBounty {
+        bounty_id: 3,
+        claimant: claimant.authority,
+        deliverable_hash: [9; 32],
+    });
+
+    // Approving records the competence gain against the bounty ID
+    let applied = task.complete(&mut claimant, &mut total_competence, &mut ledger, 300).unwrap();
+    assert_eq!(applied, (5 * SCALING_FACTOR) as i64);
+    assert_eq!(claimant.competence_score, GENESIS_COMPETENCE + 5 * SCALING_FACTOR);
+    assert_eq!(total_competence, GENESIS_COMPETENCE + 5 * SCALING_FACTOR);
+    assert!(task.status == BountyStatus::Completed);
+    assert_eq!(task.completed_at, 300);
+
+    let entry = ledger.entries[0];
+    assert!(entry.reason == ReputationReason::Bounty);
+    assert!(entry.score == ScoreKind::Competence);
+    assert_eq!(entry.reference_id, Some(3));
+    assert_eq!(entry.delta, applied);
+}
@@ -0,0 +1,132 @@
+use crate::fixtures::bounty;
+use anchor_lang::prelude::Pubkey;
+use dao::{
+    treasury_token_address, BountyStatus, DaoConfig, ProposalAction, ProposalType, TokenSpendThreshold,
+    DEFAULT_CRITICAL_SPEND_THRESHOLD,
+};
+
+// Treasury rules:
+// - Anyone can deposit SOL into the treasury vault
+// - Withdrawals only run as the action of a passed proposal
+// - Amounts above `critical_spend_threshold` need a Critical proposal
+// - SPL tokens sit in associated token accounts owned by the treasury PDA
+// - Token transfers above the mint's threshold, or of a mint without one, need a Critical proposal
+// - Bounty rewards above the threshold can only be approved by a Critical proposal
+// - A bounty proposal approves one claimant's deliverable; reviewers cannot claim
+
+fn required_type(config: &DaoConfig, amount: u64) -> ProposalType {
+    if config.requires_critical(amount) {
+        ProposalType::Critical
+    } else {
+        ProposalType::Operational
+    }
+}
+
+#[test]
+fn test_spend_threshold() {
+    let config = DaoConfig::default();
+    assert_eq!(config.critical_spend_threshold, DEFAULT_CRITICAL_SPEND_THRESHOLD);
+
+    // Room rental: small spend, relative majority is enough
+    assert!(required_type(&config, 2_000_000_000) == ProposalType::Operational);
+    // Exactly at the threshold still passes as Operational
+    assert!(required_type(&config, DEFAULT_CRITICAL_SPEND_THRESHOLD) == ProposalType::Operational);
+    // Equipment purchase above the threshold needs an absolute majority
+    assert!(required_type(&config, DEFAULT_CRITICAL_SPEND_THRESHOLD + 1) == ProposalType::Critical);
+}
+
+#[test]
+fn test_configurable_threshold() {
+    let config = DaoConfig { critical_spend_threshold: 0, ..DaoConfig::default() };
+
+    // With a zero threshold every spend is Critical
+    assert!(config.requires_critical(1));
+    assert!(config.validate().is_ok());
+    println!("Critical spend threshold: {} lamports", DEFAULT_CRITICAL_SPEND_THRESHOLD);
+}
+
+#[test]
+fn test_treasury_token_accounts() {
+    let usdc = Pubkey::new_unique();
+    let token = Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    let token_2022 = Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+    // One deterministic account per mint and token program
+    assert_eq!(treasury_token_address(&usdc, &token), treasury_token_address(&usdc, &token));
+    assert_ne!(treasury_token_address(&usdc, &token), treasury_token_address(&usdc, &token_2022));
+    assert_ne!(treasury_token_address(&usdc, &token), treasury_token_address(&Pubkey::new_unique(), &token));
+}
+
+#[test]
+fn test_token_transfer_payload() {
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let action = ProposalAction::TokenTransfer { mint, destination, amount: 250_000_000 };
+
+    // Executing with a different mint, destination or amount is rejected
+    assert!(action == ProposalAction::TokenTransfer { mint, destination, amount: 250_000_000 });
+    assert!(action != ProposalAction::TokenTransfer { mint: Pubkey::new_unique(), destination, amount: 250_000_000 });
+    assert!(action != ProposalAction::TokenTransfer { mint, destination: Pubkey::new_unique(), amount: 250_000_000 });
+    assert!(action != ProposalAction::TokenTransfer { mint, destination, amount: 1 });
+    assert!(action != ProposalAction::TreasuryWithdrawal { recipient: destination, amount: 250_000_000 });
+}
+
+#[test]
+fn test_token_spend_threshold() {
+    let usdc = Pubkey::new_unique();
+    let mut config = DaoConfig::default();
+
+    // Without a threshold, every transfer of the mint is Critical
+    assert!(config.requires_critical_token_transfer(&usdc, 1));
+    assert!(!config.requires_critical_token_transfer(&usdc, 0));
+
+    config.token_spend_thresholds[0] = TokenSpendThreshold { mint: usdc, amount: 1_000_000_000 };
+    assert!(config.validate().is_ok());
+    assert!(!config.requires_critical_token_transfer(&usdc, 1_000_000_000));
+    assert!(config.requires_critical_token_transfer(&usdc, 1_000_000_001));
+    // Other mints keep needing a Critical proposal
+    assert!(config.requires_critical_token_transfer(&Pubkey::new_unique(), 1));
+    // Unused entries never match
+    assert!(config.requires_critical_token_transfer(&Pubkey::default(), 1));
+
+    // A mint can only have one threshold
+    config.token_spend_thresholds[1] = TokenSpendThreshold { mint: usdc, amount: 1 };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_bounty_approval_route() {
+    let config = DaoConfig::default();
+    let reviewer = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+    let mut task = bounty(500_000_000, Some(reviewer));
+
+    // The reviewer cannot claim the bounty they approve
+    assert!(task.check_claimable(&reviewer).is_err());
+    task.check_claimable(&claimant).unwrap();
+    task.status = BountyStatus::Submitted;
+    task.claimant = Some(claimant);
+    task.deliverable_hash = [7; 32];
+    assert!(task.check_claimable(&Pubkey::new_unique()).is_err(), "Already taken");
+
+    // A reviewer can approve small rewards alone, anyone else needs a proposal
+    assert!(!task.requires_proposal(&reviewer, &config));
+    assert!(task.requires_proposal(&claimant, &config));
+    // Large rewards go through a proposal, reviewer or not
+    task.reward = DEFAULT_CRITICAL_SPEND_THRESHOLD * 2;
+    assert!(task.requires_proposal(&reviewer, &config));
+    assert!(required_type(&config, task.reward) == ProposalType::Critical);
+
+    // The proposal approves one claimant's deliverable: a new submission or
+    // a different claimant no longer matches the voted action
+    let voted = task.approval_action().unwrap();
+    assert!(voted == ProposalAction::ApproveBounty { bounty_id: task.id, claimant, deliverable_hash: [7; 32] });
+    task.deliverable_hash = [8; 32];
+    assert!(task.approval_action().unwrap() != voted);
+    task.deliverable_hash = [7; 32];
+    task.claimant = Some(Pubkey::new_unique());
+    assert!(task.approval_action().unwrap() != voted);
+
+    task.claimant = None;
+    assert!(task.approval_action().is_err(), "Nothing to approve without a claimant");
+}
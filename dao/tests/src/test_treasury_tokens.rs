@@ -0,0 +1,294 @@
+use std::{str::FromStr, thread, time::Duration};
+
+use anchor_client::{
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        pubkey::Pubkey,
+        signature::{read_keypair_file, Keypair},
+        signer::Signer,
+    },
+    Client, Cluster, Program,
+};
+use anchor_lang::system_program;
+use anchor_spl::{
+    associated_token::{
+        get_associated_token_address_with_program_id, spl_associated_token_account,
+    },
+    token::spl_token,
+    token_2022::spl_token_2022,
+    token_interface::TokenAccount,
+};
+use solana_system_interface::instruction::create_account;
+
+use dao::{ProposalAction, ProposalStatus, ProposalType, TokenSpendThreshold, TreasuryTransfer};
+
+const MINT_SIZE: u64 = 82;
+const MINTED: u64 = 1_000_000_000;
+const TRANSFERRED: u64 = 250_000_000;
+const VOTING_PERIOD: i64 = 5;
+
+fn get_state_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"state"], program_id)
+}
+
+fn get_member_pda(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"member", authority.as_ref()], program_id)
+}
+
+fn get_ledger_pda(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ledger", authority.as_ref()], program_id)
+}
+
+fn get_treasury_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury"], program_id)
+}
+
+fn get_proposal_pda(program_id: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"proposal", id.to_le_bytes().as_ref()], program_id)
+}
+
+fn get_vote_pda(program_id: &Pubkey, proposal_id: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vote", proposal_id.to_le_bytes().as_ref(), voter.as_ref()], program_id)
+}
+
+fn get_transfer_pda(program_id: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury_transfer", id.to_le_bytes().as_ref()], program_id)
+}
+
+/// Create a mint owned by `token_program` with the payer as mint authority
+fn create_mint<C: std::ops::Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    payer: &Pubkey,
+    token_program: &Pubkey,
+) -> Keypair {
+    let mint = Keypair::new();
+    let rent = program.rpc()
+        .get_minimum_balance_for_rent_exemption(MINT_SIZE as usize)
+        .expect("Failed to fetch rent");
+    let initialize_mint = if *token_program == spl_token::ID {
+        spl_token::instruction::initialize_mint2(token_program, &mint.pubkey(), payer, None, 6)
+    } else {
+        spl_token_2022::instruction::initialize_mint2(token_program, &mint.pubkey(), payer, None, 6)
+    }
+    .unwrap();
+
+    program
+        .request()
+        .instruction(create_account(payer, &mint.pubkey(), rent, MINT_SIZE, token_program))
+        .instruction(initialize_mint)
+        .signer(&mint)
+        .send()
+        .expect("Failed to create mint");
+    mint
+}
+
+#[test]
+fn test_treasury_token_transfer() {
+    // Needs a local validator with the program deployed and free genesis slots
+    let program_id_str = "Ft54i1cMxhkD5pvxMHfmzW8quwPZRPVQRTcqMFLXqYzi";
+    let anchor_wallet = std::env::var("ANCHOR_WALLET").unwrap();
+    let payer = read_keypair_file(&anchor_wallet).unwrap();
+
+    let client = Client::new_with_options(Cluster::Localnet, &payer, CommitmentConfig::confirmed());
+    let program_id = Pubkey::from_str(program_id_str).unwrap();
+    let program = client.program(program_id).unwrap();
+
+    let (state_pda, _) = get_state_pda(&program_id);
+    let (treasury_pda, _) = get_treasury_pda(&program_id);
+
+    // DAO with the payer as one of the 3 genesis members, and a treasury
+    let _ = program
+        .request()
+        .accounts(dao::accounts::Initialize {
+            state: state_pda,
+            authority: payer.pubkey(),
+            system_program: system_program::ID,
+        })
+        .args(dao::instruction::Initialize {})
+        .send();
+
+    let genesis_members = [payer.pubkey(), Keypair::new().pubkey(), Keypair::new().pubkey()];
+    for member in genesis_members.iter() {
+        program
+            .request()
+            .accounts(dao::accounts::AddGenesisMember {
+                state: state_pda,
+                member: get_member_pda(&program_id, member).0,
+                ledger: get_ledger_pda(&program_id, member).0,
+                member_authority: *member,
+                authority: payer.pubkey(),
+                system_program: system_program::ID,
+            })
+            .args(dao::instruction::AddGenesisMember {})
+            .send()
+            .expect("Failed to add genesis member");
+    }
+
+    let _ = program
+        .request()
+        .accounts(dao::accounts::InitializeTreasury {
+            state: state_pda,
+            treasury: treasury_pda,
+            authority: payer.pubkey(),
+            system_program: system_program::ID,
+        })
+        .args(dao::instruction::InitializeTreasury {})
+        .send();
+
+    for token_program in [spl_token::ID, spl_token_2022::ID] {
+        let mint = create_mint(&program, &payer.pubkey(), &token_program);
+        let mint_key = mint.pubkey();
+        let treasury_ata = get_associated_token_address_with_program_id(&treasury_pda, &mint_key, &token_program);
+        assert_eq!(treasury_ata, dao::treasury_token_address(&mint_key, &token_program));
+
+        // Treasury token account, funded by minting into it
+        let mint_to = if token_program == spl_token::ID {
+            spl_token::instruction::mint_to(&token_program, &mint_key, &treasury_ata, &payer.pubkey(), &[], MINTED)
+        } else {
+            spl_token_2022::instruction::mint_to(&token_program, &mint_key, &treasury_ata, &payer.pubkey(), &[], MINTED)
+        }
+        .unwrap();
+        program
+            .request()
+            .accounts(dao::accounts::CreateTreasuryTokenAccount {
+                treasury: treasury_pda,
+                mint: mint_key,
+                treasury_token_account: treasury_ata,
+                payer: payer.pubkey(),
+                token_program,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: system_program::ID,
+            })
+            .args(dao::instruction::CreateTreasuryTokenAccount {})
+            .instruction(mint_to)
+            .send()
+            .expect("Failed to create treasury token account");
+
+        let recipient = Keypair::new().pubkey();
+        let destination = get_associated_token_address_with_program_id(&recipient, &mint_key, &token_program);
+        program
+            .request()
+            .instruction(spl_associated_token_account::instruction::create_associated_token_account(
+                &payer.pubkey(), &recipient, &mint_key, &token_program,
+            ))
+            .send()
+            .expect("Failed to create destination token account");
+
+        // Give the mint a threshold so that the transfer passes as Operational
+        let mut config = program.account::<dao::State>(state_pda).unwrap().config;
+        config.token_spend_thresholds[0] = TokenSpendThreshold { mint: mint_key, amount: MINTED };
+        program
+            .request()
+            .accounts(dao::accounts::UpdateConfig { state: state_pda, authority: payer.pubkey() })
+            .args(dao::instruction::UpdateConfig { config })
+            .send()
+            .expect("Failed to set the token spend threshold");
+
+        // Proposal, vote and tally
+        let proposal_id = program.account::<dao::State>(state_pda).unwrap().proposal_counter;
+        let (proposal_pda, _) = get_proposal_pda(&program_id, proposal_id);
+        let action = ProposalAction::TokenTransfer { mint: mint_key, destination, amount: TRANSFERRED };
+        program
+            .request()
+            .accounts(dao::accounts::CreateProposal {
+                state: state_pda,
+                proposal: proposal_pda,
+                member: get_member_pda(&program_id, &payer.pubkey()).0,
+                skill_domains: None,
+                proposer: payer.pubkey(),
+                system_program: system_program::ID,
+            })
+            .args(dao::instruction::CreateProposal {
+                title: "Pay the venue".to_string(),
+                description: "Token transfer from the treasury".to_string(),
+                proposal_type: ProposalType::Operational,
+                voting_period: VOTING_PERIOD,
+                domain: None,
+                action: Some(action),
+            })
+            .send()
+            .expect("Failed to create proposal");
+
+        program
+            .request()
+            .accounts(dao::accounts::Vote {
+                state: state_pda,
+                proposal: proposal_pda,
+                member: get_member_pda(&program_id, &payer.pubkey()).0,
+                vote_record: get_vote_pda(&program_id, proposal_id, &payer.pubkey()).0,
+                competence_profile: None,
+                skill_domains: None,
+                voter: payer.pubkey(),
+                system_program: system_program::ID,
+            })
+            .args(dao::instruction::Vote { support: true })
+            .send()
+            .expect("Failed to vote");
+
+        thread::sleep(Duration::from_secs(VOTING_PERIOD as u64 + 2));
+        program
+            .request()
+            .accounts(dao::accounts::ExecuteProposal {
+                state: state_pda,
+                proposal: proposal_pda,
+                executor: payer.pubkey(),
+            })
+            .args(dao::instruction::ExecuteProposal {})
+            .send()
+            .expect("Failed to tally proposal");
+        let proposal: dao::Proposal = program.account(proposal_pda).unwrap();
+        assert!(proposal.status == ProposalStatus::Passed);
+
+        // Transfer through the program's CPI, signed by the treasury PDA
+        let transfer_id = program.account::<dao::Treasury>(treasury_pda).unwrap().transfer_count;
+        let (transfer_pda, _) = get_transfer_pda(&program_id, transfer_id);
+        let tx = program
+            .request()
+            .accounts(dao::accounts::WithdrawTreasuryTokens {
+                state: state_pda,
+                treasury: treasury_pda,
+                proposal: proposal_pda,
+                mint: mint_key,
+                treasury_token_account: treasury_ata,
+                destination,
+                transfer_record: transfer_pda,
+                executor: payer.pubkey(),
+                token_program,
+                system_program: system_program::ID,
+            })
+            .args(dao::instruction::WithdrawTreasuryTokens {})
+            .send()
+            .expect("Failed to withdraw treasury tokens");
+        println!("Treasury token transfer ({}): {}", token_program, tx);
+
+        let treasury_balance: TokenAccount = program.account(treasury_ata).unwrap();
+        let destination_balance: TokenAccount = program.account(destination).unwrap();
+        assert_eq!(treasury_balance.amount, MINTED - TRANSFERRED);
+        assert_eq!(destination_balance.amount, TRANSFERRED);
+
+        let record: TreasuryTransfer = program.account(transfer_pda).unwrap();
+        assert_eq!(record.proposal_id, Some(proposal_id));
+        assert_eq!(record.mint, Some(mint_key));
+        assert_eq!(record.recipient, destination);
+        assert_eq!(record.amount, TRANSFERRED);
+
+        // The proposal cannot be executed twice
+        let replay = program
+            .request()
+            .accounts(dao::accounts::WithdrawTreasuryTokens {
+                state: state_pda,
+                treasury: treasury_pda,
+                proposal: proposal_pda,
+                mint: mint_key,
+                treasury_token_account: treasury_ata,
+                destination,
+                transfer_record: get_transfer_pda(&program_id, transfer_id + 1).0,
+                executor: payer.pubkey(),
+                token_program,
+                system_program: system_program::ID,
+            })
+            .args(dao::instruction::WithdrawTreasuryTokens {})
+            .send();
+        assert!(replay.is_err(), "Proposal must not be reusable");
+    }
+}